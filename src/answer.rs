@@ -0,0 +1,88 @@
+use std::fmt::{self, Display};
+
+/// Answer given by a part of a solution.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+	Integer(i64),
+	BigInteger(i128),
+	Text(String),
+	/// Letters drawn on several lines, that needs to be read by a human
+	Image(String),
+	/// The part has no answer (e.g. the last day of a year only has one puzzle)
+	None,
+}
+
+impl Answer {
+	pub fn is_none(&self) -> bool {
+		matches!(self, Self::None)
+	}
+
+	/// Raw value of the answer, as it would be submitted
+	pub fn value(&self) -> Option<String> {
+		match self {
+			Self::Integer(n) => Some(n.to_string()),
+			Self::BigInteger(n) => Some(n.to_string()),
+			Self::Text(s) | Self::Image(s) => Some(s.clone()),
+			Self::None => None,
+		}
+	}
+}
+
+impl Display for Answer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Integer(n) => write!(f, "{n}"),
+			Self::BigInteger(n) => write!(f, "{n}"),
+			Self::Text(s) => write!(f, "{s}"),
+			Self::Image(s) => write!(f, "\n{s}"),
+			Self::None => write!(f, "-"),
+		}
+	}
+}
+
+macro_rules! from_integer {
+	($($t:ty),*) => {
+		$(impl From<$t> for Answer {
+			fn from(value: $t) -> Self {
+				match i64::try_from(value) {
+					Ok(n) => Self::Integer(n),
+					Err(_) => Self::BigInteger(value as i128),
+				}
+			}
+		})*
+	};
+}
+
+from_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, i128);
+
+impl From<String> for Answer {
+	fn from(value: String) -> Self {
+		Self::Text(value)
+	}
+}
+
+impl From<&str> for Answer {
+	fn from(value: &str) -> Self {
+		Self::Text(value.to_string())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_integers() {
+		assert_eq!(Answer::from(42u8), Answer::Integer(42));
+		assert_eq!(Answer::from(-3i32), Answer::Integer(-3));
+		assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+		assert_eq!(Answer::from(12usize), Answer::from(12i64));
+	}
+
+	#[test]
+	fn display() {
+		assert_eq!(Answer::from("abc").to_string(), "abc");
+		assert_eq!(Answer::Image("#.\n.#".to_string()).to_string(), "\n#.\n.#");
+		assert_eq!(Answer::None.to_string(), "-");
+	}
+}
@@ -0,0 +1,236 @@
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+use colored::Colorize;
+
+use crate::history::HistoryOptions;
+use crate::runner::solve_with_limit;
+use crate::solution::Solution;
+use crate::utils::colored_time;
+
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+	/// Runs done before measuring, to warm up caches
+	pub warmup: usize,
+	/// Maximum amount of measured runs
+	pub runs: usize,
+	/// Stop measuring once this time is spent on a day (at least one run is always done)
+	pub budget: Duration,
+	/// Only measure this part, the other one is not run
+	pub part: Option<u8>,
+	/// Time limit of each step, measuring stops at the first run exceeding it
+	pub timeout: Option<Duration>,
+	/// Path of an input to use instead of the cached ones, `-` for the standard input
+	pub input: Option<String>,
+	pub history: HistoryOptions,
+}
+
+impl Default for BenchOptions {
+	fn default() -> Self {
+		Self {
+			warmup: 1,
+			runs: 100,
+			budget: Duration::from_secs(5),
+			part: None,
+			timeout: None,
+			input: None,
+			history: HistoryOptions::default(),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Stats {
+	/// Amount of measured runs
+	pub samples: usize,
+	pub min: Duration,
+	pub median: Duration,
+	pub mean: Duration,
+	pub stddev: Duration,
+}
+
+impl Stats {
+	pub fn new(samples: &[Duration]) -> Self {
+		if samples.is_empty() {
+			return Self::default();
+		}
+
+		let mut sorted = samples.to_vec();
+		sorted.sort();
+
+		let n = sorted.len();
+		let median = if n.is_multiple_of(2) {
+			(sorted[n / 2 - 1] + sorted[n / 2]) / 2
+		} else {
+			sorted[n / 2]
+		};
+
+		let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+		let variance = sorted.iter().map(|d| (d.as_secs_f64() - mean).powi(2)).sum::<f64>() / n as f64;
+
+		Self {
+			samples: n,
+			min: sorted[0],
+			median,
+			mean: Duration::from_secs_f64(mean),
+			stddev: Duration::from_secs_f64(variance.sqrt()),
+		}
+	}
+}
+
+impl Display for Stats {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"min {}, median {}, mean {}, stddev {:.2?}",
+			colored_time(self.min),
+			colored_time(self.median),
+			colored_time(self.mean),
+			self.stddev
+		)
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayBench {
+	pub year: u16,
+	pub day: u8,
+	pub runs: usize,
+	pub parse: Stats,
+	pub part1: Stats,
+	pub part2: Stats,
+	/// A run exceeded the time limit, it's not part of the statistics
+	pub timed_out: bool,
+}
+
+impl DayBench {
+	pub fn total(&self) -> Duration {
+		self.parse.median + self.part1.median + self.part2.median
+	}
+
+	/// Median time of each step, for the steps measured at least once
+	pub fn timings(&self) -> [Option<Duration>; 3] {
+		[self.parse, self.part1, self.part2].map(|stats| (stats.samples > 0).then_some(stats.median))
+	}
+}
+
+impl Display for DayBench {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.timed_out {
+			writeln!(f, "d{:02} ({} runs, {}):", self.day, self.runs, "stopped by the time limit".red())?;
+		} else {
+			writeln!(f, "d{:02} ({} runs):", self.day, self.runs)?;
+		}
+		writeln!(f, "  parse  {}", self.parse)?;
+		writeln!(f, "  part1  {}", self.part1)?;
+		write!(f, "  part2  {}", self.part2)
+	}
+}
+
+/// Run a day several times and gather timing statistics of each step, until a run exceeds the time limit
+pub fn bench_day(solution: &'static dyn Solution, input: &str, options: &BenchOptions) -> DayBench {
+	let run = || solve_with_limit(solution, input, options.part, options.timeout);
+	let mut timed_out = (0..options.warmup).any(|_| run().has_timed_out());
+
+	let (mut runs, mut parse, mut part1, mut part2) = (0, Vec::new(), Vec::new(), Vec::new());
+	let start = Instant::now();
+	while !timed_out && runs < options.runs.max(1) && (runs == 0 || start.elapsed() < options.budget) {
+		let res = run();
+		if res.has_timed_out() {
+			timed_out = true;
+			break;
+		}
+		runs += 1;
+
+		// Steps which aren't run or fail are not measured
+		let [p, p1, p2] = res.timings();
+		parse.extend(p);
+		part1.extend(p1);
+		part2.extend(p2);
+	}
+
+	DayBench {
+		year: solution.year(),
+		day: solution.day(),
+		runs,
+		parse: Stats::new(&parse),
+		part1: Stats::new(&part1),
+		part2: Stats::new(&part2),
+		timed_out,
+	}
+}
+
+/// Sum of the medians of several days
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Total {
+	pub parse: Duration,
+	pub part1: Duration,
+	pub part2: Duration,
+}
+
+impl Total {
+	pub fn add(&mut self, day: &DayBench) {
+		self.parse += day.parse.median;
+		self.part1 += day.part1.median;
+		self.part2 += day.part2.median;
+	}
+}
+
+impl Display for Total {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"total: parse {}, part1 {}, part2 {}, all {}",
+			colored_time(self.parse),
+			colored_time(self.part1),
+			colored_time(self.part2),
+			colored_time(self.parse + self.part1 + self.part2)
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn ms(values: &[u64]) -> Vec<Duration> {
+		values.iter().map(|v| Duration::from_millis(*v)).collect()
+	}
+
+	#[test]
+	fn stats() {
+		let stats = Stats::new(&ms(&[4, 2, 6, 4, 4, 5, 7, 9]));
+		assert_eq!(stats.min, Duration::from_millis(2));
+		assert_eq!(stats.median, Duration::from_millis(4) + Duration::from_micros(500));
+		assert_eq!(stats.mean.as_millis(), 5);
+		assert_eq!(stats.stddev.as_millis(), 2);
+
+		let stats = Stats::new(&ms(&[3, 1, 2]));
+		assert_eq!(stats.median, Duration::from_millis(2));
+		assert_eq!(Stats::new(&[]), Stats::default());
+	}
+
+	#[test]
+	fn bench_runs() {
+		let solution = crate::find(2024, 1).unwrap();
+		let options = BenchOptions {
+			warmup: 0,
+			runs: 3,
+			budget: Duration::from_secs(60),
+			..Default::default()
+		};
+		let res = bench_day(solution, "3   4\n4   3", &options);
+		assert_eq!((res.year, res.day, res.runs), (2024, 1, 3));
+		assert!(res.parse.min <= res.parse.median);
+		assert!(!res.timed_out);
+
+		let options = BenchOptions {
+			timeout: Some(Duration::from_millis(50)),
+			..options
+		};
+		let res = bench_day(&crate::runner::tests::Slow, "", &options);
+		assert_eq!(res.runs, 0);
+		assert!(res.timed_out);
+		assert!(res.to_string().contains("stopped by the time limit"));
+	}
+}
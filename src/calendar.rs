@@ -0,0 +1,137 @@
+use std::fmt::{self, Display};
+use std::path::Path;
+
+use colored::Colorize;
+
+use crate::solution::Solution;
+use crate::utils::{event_days, input_path};
+use crate::verify::AnswerStore;
+
+/// Days shown on each line of the calendar
+const WEEK: u8 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PartStatus {
+	/// The answer is known
+	Verified,
+	Unverified,
+	/// The part returns a constant instead of an answer
+	Placeholder,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DayStatus {
+	day: u8,
+	/// Input cached locally
+	input: bool,
+	/// Status of each part, `None` if the day is not solved
+	parts: Option<[PartStatus; 2]>,
+}
+
+impl Display for DayStatus {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let Some(parts) = self.parts else {
+			return write!(f, "{}", format!("{:02}    ", self.day).dimmed());
+		};
+
+		let day = format!("{:02}", self.day);
+		let day = if parts.iter().all(|p| *p != PartStatus::Unverified) {
+			day.green()
+		} else {
+			day.yellow()
+		};
+		let input = if self.input { "i".normal() } else { "·".dimmed() };
+		write!(f, "{day} {input}")?;
+
+		for part in parts {
+			match part {
+				PartStatus::Verified => write!(f, "{}", "✓".green())?,
+				PartStatus::Unverified => write!(f, "?")?,
+				PartStatus::Placeholder => write!(f, "{}", "~".yellow())?,
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Implementation status of each day of a year
+pub struct Calendar {
+	year: u16,
+	days: Vec<DayStatus>,
+}
+
+impl Calendar {
+	pub fn new(year: u16, solutions: &[&dyn Solution], answers: &AnswerStore) -> Self {
+		let days = (1..=event_days(year))
+			.map(|day| {
+				let parts = solutions.iter().find(|s| s.day() == day).map(|solution| {
+					std::array::from_fn(|i| {
+						let part = i as u8 + 1;
+						if solution.placeholder() == Some(part) {
+							PartStatus::Placeholder
+						} else if answers.get(year, day, part).is_some() {
+							PartStatus::Verified
+						} else {
+							PartStatus::Unverified
+						}
+					})
+				});
+
+				DayStatus {
+					day,
+					input: Path::new(&input_path(year, day)).is_file(),
+					parts,
+				}
+			})
+			.collect();
+
+		Self { year, days }
+	}
+}
+
+impl Display for Calendar {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let solved = self.days.iter().filter(|d| d.parts.is_some()).count();
+		write!(f, "{} ({solved}/{} days)", self.year.to_string().bold(), self.days.len())?;
+
+		for (i, day) in self.days.iter().enumerate() {
+			if i % WEEK as usize == 0 {
+				write!(f, "\n ")?;
+			}
+			write!(f, "  {day}")?;
+		}
+		Ok(())
+	}
+}
+
+/// Meaning of the marks of the calendar
+pub const LEGEND: &str = "i input cached, ✓ answer verified, ? answer unknown, ~ placeholder";
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::tests::TempDir;
+
+	#[test]
+	fn calendar() {
+		let dir = TempDir::new("calendar");
+		std::fs::create_dir_all(&dir).unwrap();
+		std::fs::write(
+			dir.join("2025.json"),
+			r#"{"01": {"part1": "1", "part2": "2"}, "12": {"part1": "3"}}"#,
+		)
+		.unwrap();
+
+		let mut answers = AnswerStore::new(&dir);
+		answers.load(2025).unwrap();
+		let calendar = Calendar::new(2025, crate::year_solutions(2025).unwrap(), &answers);
+		assert_eq!(calendar.days.len(), 12);
+		assert_eq!(calendar.days[0].parts, Some([PartStatus::Verified, PartStatus::Verified]));
+		assert_eq!(calendar.days[1].parts, Some([PartStatus::Unverified, PartStatus::Unverified]));
+		assert_eq!(calendar.days[11].parts, Some([PartStatus::Verified, PartStatus::Placeholder]));
+
+		let calendar = Calendar::new(2017, crate::year_solutions(2017).unwrap(), &answers);
+		assert_eq!(calendar.days.len(), 25);
+		assert_eq!(calendar.days[2].parts, None);
+	}
+}
@@ -0,0 +1,259 @@
+use std::fmt::{self, Display};
+use std::thread;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::header::{COOKIE, HeaderMap, HeaderValue};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable replacing the base URL, to use another server
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const USER_AGENT: &str = "github.com/seysn/advent-of-code-rust by seys.nicolas@gmail.com";
+
+#[derive(Debug)]
+pub enum ClientError {
+	/// The puzzle doesn't exist or isn't available yet
+	NotUnlocked,
+	/// A page other than a puzzle doesn't exist, with its path
+	NotFound(String),
+	/// The session is missing, invalid or has expired
+	BadSession,
+	/// Unexpected status of a response
+	Status(u16),
+	Http(reqwest::Error),
+}
+
+impl Display for ClientError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::NotUnlocked => write!(f, "the puzzle is not unlocked yet"),
+			Self::NotFound(path) => write!(f, "the page {path} doesn't exist"),
+			Self::BadSession => write!(f, "the session is missing or has expired, check it with `session check`"),
+			Self::Status(status) => write!(f, "unexpected response status {status}"),
+			Self::Http(e) => write!(f, "{e}"),
+		}
+	}
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<reqwest::Error> for ClientError {
+	fn from(e: reqwest::Error) -> Self {
+		Self::Http(e)
+	}
+}
+
+/// Client of the Advent of Code website, authenticated with a session token
+pub struct AocClient {
+	client: Client,
+	base_url: String,
+	/// Attempts after the first one when the server fails
+	retries: u32,
+	/// Delay before the first retry, doubled after each one
+	backoff: Duration,
+}
+
+impl AocClient {
+	pub fn new(session: &str) -> Result<Self, ClientError> {
+		let mut headers = HeaderMap::new();
+		let cookie = HeaderValue::from_str(&format!("session={session}")).map_err(|_| ClientError::BadSession)?;
+		headers.append(COOKIE, cookie);
+
+		let client = Client::builder()
+			.user_agent(USER_AGENT)
+			.default_headers(headers)
+			.connect_timeout(Duration::from_secs(10))
+			.timeout(Duration::from_secs(30))
+			.build()?;
+
+		Ok(Self {
+			client,
+			base_url: BASE_URL.to_string(),
+			retries: 3,
+			backoff: Duration::from_secs(1),
+		})
+	}
+
+	pub fn with_base_url(self, base_url: &str) -> Self {
+		Self {
+			base_url: base_url.trim_end_matches('/').to_string(),
+			..self
+		}
+	}
+
+	pub fn with_retries(self, retries: u32, backoff: Duration) -> Self {
+		Self { retries, backoff, ..self }
+	}
+
+	pub fn url(&self, path: &str) -> String {
+		format!("{}{path}", self.base_url)
+	}
+
+	/// Send a request, retrying it up to `retries` times with an exponential backoff when the server fails or doesn't answer
+	fn send(&self, retries: u32, request: impl Fn() -> RequestBuilder) -> Result<Response, ClientError> {
+		let mut attempt = 0;
+		let res = loop {
+			let res = request().send();
+			let failed = match &res {
+				Ok(res) => res.status().is_server_error(),
+				Err(e) => e.is_timeout() || e.is_connect(),
+			};
+			if !failed || attempt >= retries {
+				break res?;
+			}
+
+			thread::sleep(self.backoff * 2u32.pow(attempt));
+			attempt += 1;
+		};
+
+		match res.status() {
+			status if status.is_success() => Ok(res),
+			StatusCode::UNAUTHORIZED => Err(ClientError::BadSession),
+			StatusCode::BAD_REQUEST | StatusCode::NOT_FOUND => {
+				// Inputs are refused with a 400 without a valid session
+				let path = res.url().path().to_string();
+				let body = res.text().unwrap_or_default();
+				if body.contains("log in") {
+					Err(ClientError::BadSession)
+				} else if path.contains("/day/") {
+					Err(ClientError::NotUnlocked)
+				} else {
+					Err(ClientError::NotFound(path))
+				}
+			}
+			status => Err(ClientError::Status(status.as_u16())),
+		}
+	}
+
+	/// Content of the page at `path`, relative to the base URL
+	pub fn get(&self, path: &str) -> Result<Vec<u8>, ClientError> {
+		let url = self.url(path);
+		Ok(self.send(self.retries, || self.client.get(&url))?.bytes()?.to_vec())
+	}
+
+	/// Post a form to `path`, returning the page of the response.
+	/// It's never retried, the server may have handled it even when it failed.
+	pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+		let url = self.url(path);
+		Ok(self.send(0, || self.client.post(&url).form(form))?.text()?)
+	}
+
+	pub fn input(&self, year: u16, day: u8) -> Result<Vec<u8>, ClientError> {
+		self.get(&format!("/{year}/day/{day}/input"))
+	}
+
+	pub fn answer(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String, ClientError> {
+		self.post(
+			&format!("/{year}/day/{day}/answer"),
+			&[("level", &part.to_string()), ("answer", answer)],
+		)
+	}
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use super::*;
+	use std::io::{BufRead, BufReader, Read, Write};
+	use std::net::TcpListener;
+	use std::thread::JoinHandle;
+
+	/// Serve each response to a connection on a local port, in order.
+	/// Returns the base URL of the server and the requests it received, as their first line and body.
+	pub(crate) fn serve(responses: Vec<(u16, String)>) -> (String, JoinHandle<Vec<(String, String)>>) {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let url = format!("http://{}", listener.local_addr().unwrap());
+
+		let server = thread::spawn(move || {
+			let mut requests = Vec::new();
+			for (status, body) in responses {
+				let (mut stream, _) = listener.accept().unwrap();
+				let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+				let mut request = String::new();
+				reader.read_line(&mut request).unwrap();
+				let mut length = 0;
+				loop {
+					let mut header = String::new();
+					reader.read_line(&mut header).unwrap();
+					if header.trim().is_empty() {
+						break;
+					}
+					if let Some((name, value)) = header.split_once(':')
+						&& name.eq_ignore_ascii_case("content-length")
+					{
+						length = value.trim().parse().unwrap();
+					}
+				}
+				let mut content = vec![0; length];
+				reader.read_exact(&mut content).unwrap();
+				requests.push((request.trim().to_string(), String::from_utf8(content).unwrap()));
+
+				write!(
+					stream,
+					"HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+					body.len()
+				)
+				.unwrap();
+			}
+			requests
+		});
+
+		(url, server)
+	}
+
+	fn client(url: &str) -> AocClient {
+		AocClient::new("abc")
+			.unwrap()
+			.with_base_url(url)
+			.with_retries(2, Duration::from_millis(1))
+	}
+
+	#[test]
+	fn retry_server_errors() {
+		let (url, server) = serve(vec![(502, String::new()), (503, String::new()), (200, "1122\n".to_string())]);
+		assert_eq!(client(&url).input(2017, 1).unwrap(), b"1122\n");
+
+		let requests = server.join().unwrap();
+		assert_eq!(requests.len(), 3);
+		assert_eq!(requests[2].0, "GET /2017/day/1/input HTTP/1.1");
+
+		let (url, server) = serve(vec![(500, String::new()); 3]);
+		assert!(matches!(client(&url).input(2017, 1), Err(ClientError::Status(500))));
+		server.join().unwrap();
+
+		let (url, server) = serve(vec![(502, String::new())]);
+		assert!(matches!(client(&url).answer(2017, 1, 1, "1234"), Err(ClientError::Status(502))));
+		assert_eq!(server.join().unwrap().len(), 1);
+	}
+
+	#[test]
+	fn errors() {
+		let (url, server) = serve(vec![
+			(404, "Please don't repeatedly request this endpoint before it unlocks!".to_string()),
+			(
+				400,
+				"Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string(),
+			),
+			(401, String::new()),
+			(200, "<article><p>That's the right answer!</p></article>".to_string()),
+			(404, String::new()),
+		]);
+		let client = client(&url);
+		assert!(matches!(client.input(2030, 1), Err(ClientError::NotUnlocked)));
+		assert!(matches!(client.input(2017, 1), Err(ClientError::BadSession)));
+		assert!(matches!(client.get("/settings"), Err(ClientError::BadSession)));
+		assert!(client.answer(2017, 1, 2, "1234").unwrap().contains("right answer"));
+		assert!(matches!(
+			client.get("/2017/leaderboard/private/view/12.json"),
+			Err(ClientError::NotFound(path)) if path == "/2017/leaderboard/private/view/12.json"
+		));
+
+		let requests = server.join().unwrap();
+		assert_eq!(
+			requests[3],
+			("POST /2017/day/1/answer HTTP/1.1".to_string(), "level=2&answer=1234".to_string())
+		);
+	}
+}
@@ -0,0 +1,59 @@
+use std::fmt::{self, Display};
+
+use crate::client::ClientError;
+use crate::utils::{current_event_year, event_days};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+	UnknownYear(u16),
+	UnknownDay(u16, u8),
+	/// A custom input couldn't be read, with its path and the reason
+	Input(String, String),
+	/// The input or the description of a day couldn't be downloaded, with the reason
+	Download(u16, u8, String),
+	/// A file couldn't be read or written, with its path and the reason
+	Io(String, String),
+	/// The session can't be used, with the reason
+	Session(String),
+	/// An answer wasn't sent, with the reason
+	Refused(String),
+	/// A request to the website failed, with the reason
+	Client(String),
+	/// There's no event this year, yet
+	InvalidYear(u16),
+	InvalidDay(u16, u8),
+	/// Every day of the year already has a solution
+	YearDone(u16),
+	/// The file of a new day already exists
+	Exists(String),
+	/// The list of years is missing from this file
+	Unregistered(String),
+}
+
+impl Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::UnknownYear(year) => write!(f, "year {year} has no solutions"),
+			Self::UnknownDay(year, day) => write!(f, "day {day} of {year} has no solution"),
+			Self::Input(path, reason) => write!(f, "unable to read input {path}: {reason}"),
+			Self::Session(reason) => write!(f, "invalid session: {reason}"),
+			Self::Client(e) => write!(f, "{e}"),
+			Self::Refused(reason) => write!(f, "answer not sent: {reason}"),
+			Self::Download(year, day, reason) => write!(f, "unable to download day {day:02} of {year}: {reason}"),
+			Self::Io(path, reason) => write!(f, "unable to access {path}: {reason}"),
+			Self::InvalidYear(year) => write!(f, "year must be between 2015 and {}, got {year}", current_event_year()),
+			Self::InvalidDay(year, day) => write!(f, "day must be between 1 and {} in {year}, got {day}", event_days(*year)),
+			Self::YearDone(year) => write!(f, "every day of {year} is already there"),
+			Self::Exists(path) => write!(f, "{path} already exists"),
+			Self::Unregistered(path) => write!(f, "unable to find the list of years in {path}"),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl From<ClientError> for Error {
+	fn from(e: ClientError) -> Self {
+		Self::Client(e.to_string())
+	}
+}
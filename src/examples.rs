@@ -0,0 +1,250 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::puzzle;
+use crate::runner::DayResult;
+use crate::verify::{Verdict, check};
+
+/// Folder of the examples, stored as `{year}/dayNN-K.txt` along with their expected answers in
+/// `{year}/dayNN-K.json`: `{"part1": "...", "part2": "..."}`
+pub const EXAMPLES_DIR: &str = "./data/examples";
+
+/// Example input of a puzzle description, with its expected answers
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+	pub year: u16,
+	pub day: u8,
+	/// Number of the example among the ones of its day
+	pub index: u8,
+	pub input: String,
+	/// Expected answer of each part, missing if the example doesn't apply to it
+	pub expected: [Option<String>; 2],
+}
+
+impl Example {
+	/// Part the example applies to, both when missing
+	pub fn part(&self) -> Option<u8> {
+		match &self.expected {
+			[Some(_), None] => Some(1),
+			[None, Some(_)] => Some(2),
+			_ => None,
+		}
+	}
+
+	pub fn verify(&self, result: &DayResult) -> [Verdict; 2] {
+		[
+			check(self.expected[0].as_deref(), &result.part1.answer),
+			check(self.expected[1].as_deref(), &result.part2.answer),
+		]
+	}
+}
+
+fn expected_answers(path: &Path) -> io::Result<[Option<String>; 2]> {
+	let content = match fs::read_to_string(path) {
+		Ok(content) => content,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok([None, None]),
+		Err(e) => return Err(e),
+	};
+	let json: Value = serde_json::from_str(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+	let part = |key: &str| match json.get(key) {
+		None | Some(Value::Null) => None,
+		Some(Value::String(s)) => Some(s.clone()),
+		Some(value) => Some(value.to_string()),
+	};
+	Ok([part("part1"), part("part2")])
+}
+
+/// Examples of a puzzle page.
+/// The example of a part is its first block of code following the word "example", its answer the last emphasised code of the part.
+/// A part without a block of code uses the example of the previous one.
+/// This is a guess: an answer emphasised before other values, or a part with several examples each having their own answer,
+/// gives a wrong expected answer, so the extracted files need to be checked.
+pub fn extract(year: u16, day: u8, page: &str) -> Vec<Example> {
+	let mut examples: Vec<Example> = Vec::new();
+	for (part, article) in puzzle::articles(page).into_iter().take(2).enumerate() {
+		let answer = puzzle::emphasised_code(article).pop();
+		let blocks = puzzle::code_blocks(article);
+		let input = blocks
+			.iter()
+			.find(|(before, _)| before.to_lowercase().contains("example"))
+			.or(blocks.first())
+			.map(|(_, input)| input.clone());
+
+		let existing = match &input {
+			Some(input) => examples.iter_mut().find(|e| e.input == *input),
+			None => examples.last_mut(),
+		};
+		match (existing, input) {
+			(Some(example), _) => example.expected[part] = answer,
+			(None, Some(input)) => {
+				let mut expected = [None, None];
+				expected[part] = answer;
+				examples.push(Example {
+					year,
+					day,
+					index: examples.len() as u8 + 1,
+					input,
+					expected,
+				});
+			}
+			(None, None) => (),
+		}
+	}
+
+	examples
+}
+
+/// Write an example in `dir` with the next number of its day, unless the same input is already there.
+/// Returns the number of the example in `dir` and the written files.
+pub fn save(dir: &Path, example: &Example) -> io::Result<(u8, Vec<PathBuf>)> {
+	let existing: Vec<Example> = load(dir, example.year)?.into_iter().filter(|e| e.day == example.day).collect();
+	if let Some(same) = existing.iter().find(|e| e.input == example.input) {
+		return Ok((same.index, Vec::new()));
+	}
+
+	let index = existing.iter().map(|e| e.index).max().unwrap_or(0) + 1;
+	let folder = dir.join(example.year.to_string());
+	let input = folder.join(format!("day{:02}-{index}.txt", example.day));
+	fs::create_dir_all(&folder)?;
+	fs::write(&input, format!("{}\n", example.input))?;
+	let mut written = vec![input.clone()];
+
+	let answers: Vec<String> = ["part1", "part2"]
+		.iter()
+		.zip(&example.expected)
+		.filter_map(|(key, expected)| Some(format!("\t\"{key}\": {}", Value::String(expected.clone()?))))
+		.collect();
+	if !answers.is_empty() {
+		let json = input.with_extension("json");
+		fs::write(&json, format!("{{\n{}\n}}\n", answers.join(",\n")))?;
+		written.push(json);
+	}
+
+	Ok((index, written))
+}
+
+/// Examples of a year stored in `dir`, sorted by day and number
+pub fn load(dir: &Path, year: u16) -> io::Result<Vec<Example>> {
+	let folder = dir.join(year.to_string());
+	if !folder.is_dir() {
+		return Ok(Vec::new());
+	}
+
+	let file = Regex::new(r"^day(\d{2})-(\d+)\.txt$").unwrap();
+	let mut examples = Vec::new();
+	for entry in fs::read_dir(folder)? {
+		let path = entry?.path();
+		let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+		let Some(c) = file.captures(&name) else {
+			continue;
+		};
+
+		examples.push(Example {
+			year,
+			day: c[1].parse().unwrap(),
+			index: c[2].parse().unwrap(),
+			// Lines may end with meaningful spaces
+			input: fs::read_to_string(&path)?.trim_end_matches(['\n', '\r']).to_string(),
+			expected: expected_answers(&path.with_extension("json"))?,
+		});
+	}
+	examples.sort_by_key(|e| (e.day, e.index));
+
+	Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::runner::solve_with;
+	use crate::utils::tests::TempDir;
+
+	/// Every example of `./data/examples` gives its expected answers
+	#[test]
+	fn examples() {
+		let mut failures = Vec::new();
+		for year in crate::years() {
+			for example in load(Path::new(EXAMPLES_DIR), year).unwrap() {
+				let Ok(solution) = crate::find(example.year, example.day) else {
+					failures.push(format!("{} d{:02} #{}: no solution", example.year, example.day, example.index));
+					continue;
+				};
+
+				let result = solve_with(solution, &example.input, example.part());
+				for (part, verdict) in example.verify(&result).iter().enumerate() {
+					if let Verdict::Wrong { expected } = verdict {
+						failures.push(format!(
+							"{} d{:02} #{} part {}: got {:?}, expected {expected}",
+							example.year,
+							example.day,
+							example.index,
+							part + 1,
+							result.parts()[part].answer
+						));
+					}
+				}
+			}
+		}
+
+		assert!(failures.is_empty(), "{}", failures.join("\n"));
+	}
+
+	#[test]
+	fn extract_examples() {
+		let page = include_str!("../tests/fixtures/puzzle.html");
+		let examples = extract(2017, 1, page);
+		assert_eq!(examples.len(), 1);
+		assert_eq!(examples[0].input, "3 4\n1 2");
+		assert_eq!(examples[0].expected, [Some("7".to_string()), Some("10".to_string())]);
+
+		let dir = TempDir::new("extract");
+		assert_eq!(save(&dir, &examples[0]).unwrap().1.len(), 2);
+		assert_eq!(save(&dir, &examples[0]).unwrap(), (1, Vec::new()));
+		assert_eq!(
+			fs::read_to_string(dir.join("2017/day01-1.json")).unwrap(),
+			"{\n\t\"part1\": \"7\",\n\t\"part2\": \"10\"\n}\n"
+		);
+		assert_eq!(load(&dir, 2017).unwrap(), examples);
+	}
+
+	#[test]
+	fn extract_several_values() {
+		let page = include_str!("../tests/fixtures/puzzle-several.html");
+		let examples = extract(2017, 3, page);
+		let found: Vec<(&str, [Option<&str>; 2])> = examples
+			.iter()
+			.map(|e| (e.input.as_str(), e.expected.each_ref().map(Option::as_deref)))
+			.collect();
+		assert_eq!(
+			found,
+			[
+				("1abc2\npqr3stu8vwx\ntreb7uchet", [Some("127"), None]),
+				("two1nine\n4nineeight", [None, Some("77")]),
+			]
+		);
+	}
+
+	#[test]
+	fn load_examples() {
+		let dir = TempDir::new("examples");
+		fs::create_dir_all(dir.join("2024")).unwrap();
+		fs::write(dir.join("2024/day02-1.txt"), "a \nb\n").unwrap();
+		fs::write(dir.join("2024/day02-1.json"), r#"{"part2": 4}"#).unwrap();
+		fs::write(dir.join("2024/day01-2.txt"), "c").unwrap();
+		fs::write(dir.join("2024/notes.txt"), "").unwrap();
+
+		let examples = load(&dir, 2024).unwrap();
+		let keys: Vec<(u8, u8)> = examples.iter().map(|e| (e.day, e.index)).collect();
+		assert_eq!(keys, [(1, 2), (2, 1)]);
+		assert_eq!(examples[0].expected, [None, None]);
+		assert_eq!(examples[1].input, "a \nb");
+		assert_eq!(examples[1].expected, [None, Some("4".to_string())]);
+		assert_eq!(examples[1].part(), Some(2));
+		assert!(load(&dir, 2023).unwrap().is_empty());
+	}
+}
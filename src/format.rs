@@ -0,0 +1,139 @@
+use std::str::FromStr;
+
+use serde_json::{Value, json};
+
+use crate::runner::{DayResult, Failure, PartResult};
+use crate::verify::Verdict;
+
+/// Output format of a run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+	/// Colored lines meant to be read by a human
+	#[default]
+	Text,
+	/// A JSON array containing a record per day
+	Json,
+	/// A CSV line per day, with a header
+	Csv,
+}
+
+impl FromStr for Format {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"text" => Ok(Self::Text),
+			"json" => Ok(Self::Json),
+			"csv" => Ok(Self::Csv),
+			_ => Err(format!("unknown format {s}, expected text, json or csv")),
+		}
+	}
+}
+
+fn day_status(result: &DayResult) -> &'static str {
+	if result.has_invalid_input() {
+		"invalid_input"
+	} else if result.has_panicked() {
+		"panicked"
+	} else if result.has_timed_out() {
+		"timed_out"
+	} else if result.is_stubbed() {
+		"stubbed"
+	} else {
+		"ok"
+	}
+}
+
+fn part_status(part: &PartResult, verdict: &Verdict) -> &'static str {
+	match (&part.answer, verdict) {
+		(Err(Failure::Panicked(_)), _) => "panicked",
+		(Err(Failure::TimedOut), _) => "timed_out",
+		(Err(Failure::Skipped), _) => "skipped",
+		(Err(Failure::Unselected), _) => "unselected",
+		(Err(Failure::Invalid(_)), _) => "invalid_input",
+		(Err(Failure::Unavailable(_)), _) => "unavailable_input",
+		(Ok(answer), _) if answer.is_none() => "none",
+		(Ok(_), Verdict::Correct) => "correct",
+		(Ok(_), Verdict::Wrong { .. }) => "wrong",
+		(Ok(_), Verdict::Unknown) => "unknown",
+	}
+}
+
+fn part_answer(part: &PartResult) -> Option<String> {
+	part.answer.as_ref().ok().and_then(|answer| answer.value())
+}
+
+pub fn json_record(result: &DayResult, verdicts: &[Verdict; 2]) -> Value {
+	json!({
+		"year": result.year,
+		"day": result.day,
+		"status": day_status(result),
+		"parse_ns": result.parse_time.as_nanos() as u64,
+		"part1": part_answer(&result.part1),
+		"part1_ns": result.part1.time.as_nanos() as u64,
+		"part1_status": part_status(&result.part1, &verdicts[0]),
+		"part2": part_answer(&result.part2),
+		"part2_ns": result.part2.time.as_nanos() as u64,
+		"part2_status": part_status(&result.part2, &verdicts[1]),
+	})
+}
+
+pub const CSV_HEADER: &str = "year,day,status,parse_ns,part1,part1_ns,part1_status,part2,part2_ns,part2_status";
+
+fn csv_field(s: &str) -> String {
+	if s.contains([',', '"', '\n', '\r']) {
+		format!("\"{}\"", s.replace('"', "\"\""))
+	} else {
+		s.to_string()
+	}
+}
+
+pub fn csv_record(result: &DayResult, verdicts: &[Verdict; 2]) -> String {
+	[
+		result.year.to_string(),
+		result.day.to_string(),
+		day_status(result).to_string(),
+		result.parse_time.as_nanos().to_string(),
+		csv_field(&part_answer(&result.part1).unwrap_or_default()),
+		result.part1.time.as_nanos().to_string(),
+		part_status(&result.part1, &verdicts[0]).to_string(),
+		csv_field(&part_answer(&result.part2).unwrap_or_default()),
+		result.part2.time.as_nanos().to_string(),
+		part_status(&result.part2, &verdicts[1]).to_string(),
+	]
+	.join(",")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::answer::Answer;
+	use crate::runner::solve;
+
+	#[test]
+	fn records() {
+		let mut result = solve(2024, 1, "3   4\n4   3").unwrap();
+		result.part2.answer = Ok(Answer::Text("a,\"b\"".to_string()));
+		let verdicts = [Verdict::Correct, Verdict::Unknown];
+
+		let json = json_record(&result, &verdicts);
+		assert_eq!(json["part1"], "0");
+		assert_eq!(json["part1_status"], "correct");
+		assert_eq!(json["part2_status"], "unknown");
+		assert_eq!(json["status"], "ok");
+
+		let csv = csv_record(&result, &verdicts);
+		assert!(csv.starts_with("2024,1,ok,"));
+		assert!(csv.contains(",\"a,\"\"b\"\"\","));
+		assert_eq!(csv.split(',').count(), CSV_HEADER.split(',').count() + 1);
+	}
+
+	#[test]
+	fn failed_records() {
+		let result = solve(2024, 1, "3   4\n4").unwrap();
+		let json = json_record(&result, &[Verdict::Unknown, Verdict::Unknown]);
+		assert_eq!(json["status"], "panicked");
+		assert_eq!(json["part1"], Value::Null);
+		assert_eq!(json["part1_status"], "skipped");
+	}
+}
@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use colored::Colorize;
+use serde_json::{Value, json};
+
+const STEPS: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Debug, Clone)]
+pub struct HistoryOptions {
+	/// Label of the timings, the current git revision is used when missing
+	pub label: Option<String>,
+	/// Label of the timings to compare with
+	pub compare: Option<String>,
+	/// Percentage above which a slower step is flagged as a regression
+	pub threshold: f64,
+	/// Append the timings to the history file
+	pub save: bool,
+	/// Amount of days solved at the same time, timings are only compared with the ones measured with as many
+	pub jobs: usize,
+}
+
+impl Default for HistoryOptions {
+	fn default() -> Self {
+		Self {
+			label: None,
+			compare: None,
+			threshold: 10.0,
+			save: true,
+			jobs: 1,
+		}
+	}
+}
+
+/// Current git revision, suffixed by `-dirty` when the tree has uncommitted changes
+fn git_revision() -> Option<String> {
+	let output = Command::new("git").args(["describe", "--always", "--dirty"]).output().ok()?;
+	if !output.status.success() {
+		return None;
+	}
+
+	Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Timings of each step, indexed by year, day and step
+type Timings = HashMap<(u16, u8, usize), Duration>;
+
+/// Timings saved in a JSON lines file, one line per step of a day
+pub struct History {
+	path: PathBuf,
+	mode: &'static str,
+	label: String,
+	threshold: f64,
+	save: bool,
+	jobs: usize,
+	baseline: Option<(String, Timings)>,
+	pending: Vec<Value>,
+	regressions: usize,
+}
+
+impl History {
+	/// `mode` separates timings of single runs from benchmark medians
+	pub fn open(path: impl Into<PathBuf>, mode: &'static str, options: &HistoryOptions) -> Self {
+		let path = path.into();
+		let label = options.label.clone().or_else(git_revision).unwrap_or_else(|| "unknown".to_string());
+
+		let baseline = options.compare.as_ref().map(|baseline| {
+			let mut timings = Timings::new();
+			let mut other_jobs = false;
+			let content = fs::read_to_string(&path).unwrap_or_default();
+			for entry in content.lines().filter_map(|l| serde_json::from_str::<Value>(l).ok()) {
+				if entry["label"].as_str() != Some(baseline) || entry["mode"].as_str() != Some(mode) {
+					continue;
+				}
+				// Timings of days solved at the same time are slowed down by each other
+				if entry["jobs"].as_u64() != Some(options.jobs as u64) {
+					other_jobs = true;
+					continue;
+				}
+
+				let (Some(year), Some(day), Some(step), Some(nanos)) = (
+					entry["year"].as_u64(),
+					entry["day"].as_u64(),
+					entry["step"].as_str().and_then(|s| STEPS.iter().position(|step| *step == s)),
+					entry["nanos"].as_u64(),
+				) else {
+					continue;
+				};
+
+				// Latest entries override the older ones
+				timings.insert((year as u16, day as u8, step), Duration::from_nanos(nanos));
+			}
+
+			if timings.is_empty() && other_jobs {
+				eprintln!(
+					"warning: timings of {baseline} were measured with another amount of jobs than {}, they are not compared",
+					options.jobs
+				);
+			}
+			(baseline.clone(), timings)
+		});
+
+		Self {
+			path,
+			mode,
+			label,
+			threshold: options.threshold,
+			save: options.save,
+			jobs: options.jobs,
+			baseline,
+			pending: Vec::new(),
+			regressions: 0,
+		}
+	}
+
+	/// Add the timings of a day, and compare them with the baseline if there's one.
+	/// Steps without timing, which weren't run or failed, are neither saved nor compared.
+	pub fn add(&mut self, year: u16, day: u8, timings: [Option<Duration>; 3]) -> Option<Comparison> {
+		let time = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map(|d| d.as_secs())
+			.unwrap_or_default();
+		for (step, duration) in STEPS.iter().zip(timings) {
+			let Some(duration) = duration else { continue };
+			self.pending.push(json!({
+				"label": self.label,
+				"mode": self.mode,
+				"year": year,
+				"day": day,
+				"step": step,
+				"nanos": duration.as_nanos() as u64,
+				"jobs": self.jobs,
+				"time": time,
+			}));
+		}
+
+		let (baseline, previous) = self.baseline.as_ref()?;
+		let changes = std::array::from_fn(|step| {
+			let before = previous.get(&(year, day, step))?.as_secs_f64();
+			let after = timings[step]?.as_secs_f64();
+			(before > 0.0).then(|| (after - before) / before * 100.0)
+		});
+
+		let comparison = Comparison {
+			baseline: baseline.clone(),
+			changes,
+			threshold: self.threshold,
+		};
+		self.regressions += comparison.regressions();
+		Some(comparison)
+	}
+
+	/// Amount of steps slower than the baseline above the threshold
+	pub fn regressions(&self) -> Option<(&str, usize)> {
+		self.baseline.as_ref().map(|(baseline, _)| (baseline.as_str(), self.regressions))
+	}
+
+	/// Append the added timings to the history file
+	pub fn save(&self) -> io::Result<()> {
+		if !self.save || self.pending.is_empty() {
+			return Ok(());
+		}
+
+		let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+		for entry in &self.pending {
+			writeln!(file, "{entry}")?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Change of each step compared to a baseline, in percent
+pub struct Comparison {
+	baseline: String,
+	changes: [Option<f64>; 3],
+	threshold: f64,
+}
+
+impl Comparison {
+	fn regressions(&self) -> usize {
+		self.changes.iter().flatten().filter(|c| **c > self.threshold).count()
+	}
+}
+
+impl Display for Comparison {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "  vs {}:", self.baseline)?;
+		for (i, (step, change)) in STEPS.iter().zip(self.changes).enumerate() {
+			let sep = if i == 0 { "" } else { "," };
+			match change {
+				Some(c) if c > self.threshold => write!(f, "{sep} {step} {} ⚠", format!("{c:+.1}%").red())?,
+				Some(c) if c < -self.threshold => write!(f, "{sep} {step} {}", format!("{c:+.1}%").green())?,
+				Some(c) => write!(f, "{sep} {step} {c:+.1}%")?,
+				None => write!(f, "{sep} {step} n/a")?,
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::tests::TempDir;
+
+	#[test]
+	fn compare_with_baseline() {
+		let dir = TempDir::new("history");
+		fs::create_dir_all(&dir).unwrap();
+		let path = dir.join("history.jsonl");
+
+		let ms = |millis| Some(Duration::from_millis(millis));
+		let options = HistoryOptions {
+			label: Some("before".to_string()),
+			..Default::default()
+		};
+		let mut history = History::open(&path, "run", &options);
+		assert!(history.add(2024, 1, [ms(10), ms(100), ms(0)]).is_none());
+		history.save().unwrap();
+
+		let options = HistoryOptions {
+			label: Some("after".to_string()),
+			compare: Some("before".to_string()),
+			..Default::default()
+		};
+		let mut history = History::open(&path, "run", &options);
+		let comparison = history.add(2024, 1, [ms(12), ms(50), ms(1)]).unwrap();
+		let rounded = comparison.changes.map(|c| c.map(f64::round));
+		assert_eq!(rounded, [Some(20.0), Some(-50.0), None]);
+		assert!(
+			history
+				.add(2024, 2, [ms(1), ms(1), ms(1)])
+				.unwrap()
+				.changes
+				.iter()
+				.all(Option::is_none)
+		);
+		assert_eq!(history.regressions(), Some(("before", 1)));
+
+		// Timings measured with another amount of jobs are not compared
+		let parallel = HistoryOptions {
+			jobs: 4,
+			..options.clone()
+		};
+		let mut history = History::open(&path, "run", &parallel);
+		assert!(
+			history
+				.add(2024, 1, [ms(12), ms(50), ms(1)])
+				.unwrap()
+				.changes
+				.iter()
+				.all(Option::is_none)
+		);
+
+		// Benchmarks are not compared with single runs
+		let mut history = History::open(&path, "bench", &options);
+		assert!(
+			history
+				.add(2024, 1, [ms(12), ms(50), ms(1)])
+				.unwrap()
+				.changes
+				.iter()
+				.all(Option::is_none)
+		);
+
+		// Steps without timing, like a part which isn't selected, keep the saved ones
+		let options = HistoryOptions {
+			label: Some("partial".to_string()),
+			..Default::default()
+		};
+		let mut history = History::open(&path, "run", &options);
+		history.add(2024, 1, [ms(10), ms(100), ms(40)]);
+		history.add(2024, 1, [ms(10), ms(100), None]);
+		history.save().unwrap();
+
+		let options = HistoryOptions {
+			compare: Some("partial".to_string()),
+			..Default::default()
+		};
+		let mut history = History::open(&path, "run", &options);
+		let comparison = history.add(2024, 1, [None, ms(100), ms(44)]).unwrap();
+		let rounded = comparison.changes.map(|c| c.map(f64::round));
+		assert_eq!(rounded, [None, Some(0.0), Some(10.0)]);
+	}
+}
@@ -0,0 +1,300 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use colored::Colorize;
+use serde_json::Value;
+
+use crate::client::AocClient;
+use crate::error::Error;
+use crate::utils::{event_days, unlock_time};
+
+/// Minimum delay between two downloads of a leaderboard, asked by the website
+pub const REFRESH: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+	pub id: u64,
+	pub name: String,
+	pub local_score: u64,
+	pub stars: u64,
+	/// Time of each star, in seconds since 1970, indexed by day
+	pub days: BTreeMap<u8, [Option<i64>; 2]>,
+}
+
+impl Member {
+	/// Time taken to get each star of a day since the puzzle was released
+	fn times(&self, year: u16, day: u8) -> [Option<i64>; 2] {
+		let stars = self.days.get(&day).copied().unwrap_or_default();
+		stars.map(|ts| ts.map(|ts| ts - unlock_time(year, day)))
+	}
+}
+
+/// Members of a private leaderboard, ranked by local score
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+	pub year: u16,
+	pub members: Vec<Member>,
+}
+
+/// Timestamps are numbers, or strings in older leaderboards
+fn timestamp(value: &Value) -> Option<i64> {
+	value.as_i64().or_else(|| value.as_str()?.parse().ok())
+}
+
+impl Leaderboard {
+	pub fn parse(year: u16, json: &str) -> Result<Self, String> {
+		let json: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+		let members = json.get("members").and_then(Value::as_object).ok_or("no members")?;
+
+		let mut members: Vec<(i64, Member)> = members
+			.values()
+			.map(|m| {
+				let id = m.get("id").and_then(Value::as_u64).unwrap_or_default();
+				let number = |key: &str| m.get(key).and_then(Value::as_u64).unwrap_or_default();
+				let days = m
+					.get("completion_day_level")
+					.and_then(Value::as_object)
+					.into_iter()
+					.flatten()
+					.filter_map(|(day, parts)| {
+						let star = |part: &str| parts.get(part)?.get("get_star_ts").and_then(timestamp);
+						Some((day.parse().ok()?, [star("1"), star("2")]))
+					})
+					.collect();
+
+				let member = Member {
+					id,
+					name: m
+						.get("name")
+						.and_then(Value::as_str)
+						.map_or_else(|| format!("(anonymous user #{id})"), str::to_string),
+					local_score: number("local_score"),
+					stars: number("stars"),
+					days,
+				};
+				(m.get("last_star_ts").and_then(timestamp).unwrap_or_default(), member)
+			})
+			.collect();
+
+		// Ties are broken by the earliest last star, like the website
+		members.sort_by_key(|(last_star, m)| (std::cmp::Reverse(m.local_score), std::cmp::Reverse(m.stars), *last_star, m.id));
+
+		Ok(Self {
+			year,
+			members: members.into_iter().map(|(_, m)| m).collect(),
+		})
+	}
+
+	/// Times of the members for the puzzle of a day
+	pub fn day(&self, day: u8) -> DayTimes<'_> {
+		DayTimes { leaderboard: self, day }
+	}
+}
+
+fn name_width(members: &[Member]) -> usize {
+	members.iter().map(|m| m.name.chars().count()).max().unwrap_or(0).max(4)
+}
+
+impl Display for Leaderboard {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let width = name_width(&self.members);
+		let days = 1..=event_days(self.year);
+
+		let tens: String = days
+			.clone()
+			.map(|d| if d >= 10 { char::from(b'0' + d / 10) } else { ' ' })
+			.collect();
+		let units: String = days.clone().map(|d| char::from(b'0' + d % 10)).collect();
+		writeln!(f, "{:20}{:width$}  {tens}", "", "")?;
+		write!(f, "rank  score  stars  {:width$}  {units}", "name")?;
+
+		for (rank, member) in self.members.iter().enumerate() {
+			write!(
+				f,
+				"\n{:>4}{:>7}{:>7}  {:width$}  ",
+				rank + 1,
+				member.local_score,
+				member.stars,
+				member.name
+			)?;
+			for day in days.clone() {
+				match member.days.get(&day) {
+					Some([Some(_), Some(_)]) => write!(f, "{}", "*".yellow())?,
+					Some([Some(_), None]) => write!(f, "{}", "*".bright_white())?,
+					_ => write!(f, "{}", ".".dimmed())?,
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Time elapsed since a puzzle was released, as `HH:MM:SS` after the number of days if there's some
+fn elapsed(secs: i64) -> String {
+	let time = format!("{:02}:{:02}:{:02}", secs % 86400 / 3600, secs % 3600 / 60, secs % 60);
+	if secs >= 86400 { format!("{}d {time}", secs / 86400) } else { time }
+}
+
+/// Times of the members of a leaderboard for a day, with the time between both parts
+pub struct DayTimes<'a> {
+	leaderboard: &'a Leaderboard,
+	day: u8,
+}
+
+impl Display for DayTimes<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (year, day) = (self.leaderboard.year, self.day);
+		let mut members: Vec<(&Member, [Option<i64>; 2])> = self
+			.leaderboard
+			.members
+			.iter()
+			.map(|m| (m, m.times(year, day)))
+			.filter(|(_, times)| times[0].is_some())
+			.collect();
+		// Members having both stars first, the fastest ones first
+		members.sort_by_key(|(_, [part1, part2])| (part2.is_none(), part2.or(*part1)));
+
+		let width = name_width(&self.leaderboard.members);
+		write!(
+			f,
+			"{year} day {day:02}\nrank  {:width$}  {:>13}  {:>13}  {:>13}",
+			"name", "part 1", "part 2", "delta"
+		)?;
+		if members.is_empty() {
+			write!(f, "\nno star yet")?;
+		}
+
+		let time = |t: Option<i64>| t.map_or_else(|| "-".to_string(), elapsed);
+		for (rank, (member, [part1, part2])) in members.iter().enumerate() {
+			let delta = part1.zip(*part2).map(|(p1, p2)| p2 - p1);
+			write!(
+				f,
+				"\n{:>4}  {:width$}  {:>13}  {:>13}  {:>13}",
+				rank + 1,
+				member.name,
+				time(*part1),
+				time(*part2),
+				time(delta)
+			)?;
+		}
+		Ok(())
+	}
+}
+
+/// Leaderboard cached in `path`, if it was downloaded less than `REFRESH` ago
+pub fn fresh(path: &Path) -> Option<String> {
+	let age = fs::metadata(path).and_then(|m| m.modified()).ok()?.elapsed().ok()?;
+	(age < REFRESH).then(|| fs::read_to_string(path).ok()).flatten()
+}
+
+/// File whose modification time is the one of the last failed download of the leaderboard cached in `path`
+fn failure_path(path: &Path) -> PathBuf {
+	path.with_extension("failed")
+}
+
+/// Download a private leaderboard and cache it in `path`.
+/// Failed downloads are recorded, it's not downloaded again less than `REFRESH` after one.
+pub fn download(client: &AocClient, path: &Path, year: u16, id: u64) -> Result<String, Error> {
+	let failure = failure_path(path);
+	if let Some(age) = fs::metadata(&failure)
+		.and_then(|m| m.modified())
+		.ok()
+		.and_then(|t| t.elapsed().ok())
+		&& age < REFRESH
+	{
+		let minutes = (REFRESH - age).as_secs().div_ceil(60);
+		return Err(Error::Client(format!(
+			"the last download of leaderboard {id} of {year} failed, try again in {minutes} minutes"
+		)));
+	}
+
+	let io = |path: &Path, e: std::io::Error| Error::Io(path.display().to_string(), e.to_string());
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent).map_err(|e| io(parent, e))?;
+	}
+
+	let json = client
+		.get(&format!("/{year}/leaderboard/private/view/{id}.json"))
+		.map_err(Error::from)
+		.and_then(|json| {
+			let json = String::from_utf8_lossy(&json).into_owned();
+			// The website answers with the page of the leaderboards when it's not allowed
+			match Leaderboard::parse(year, &json) {
+				Ok(_) => Ok(json),
+				Err(_) => Err(Error::Client(format!(
+					"leaderboard {id} of {year} is not available, the session must be one of its members"
+				))),
+			}
+		});
+	let json = match json {
+		Ok(json) => json,
+		Err(e) => {
+			fs::write(&failure, "").map_err(|e| io(&failure, e))?;
+			return Err(e);
+		}
+	};
+
+	fs::write(path, &json).map_err(|e| io(path, e))?;
+	let _ = fs::remove_file(&failure);
+
+	Ok(json)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::client::tests::serve;
+	use crate::utils::tests::TempDir;
+
+	const JSON: &str = include_str!("../tests/fixtures/leaderboard.json");
+
+	#[test]
+	fn ranking() {
+		let leaderboard = Leaderboard::parse(2017, JSON).unwrap();
+		let names: Vec<&str> = leaderboard.members.iter().map(|m| m.name.as_str()).collect();
+		assert_eq!(names, ["Alice", "Bob", "(anonymous user #3)"]);
+		assert_eq!(leaderboard.members[0].days[&2], [Some(1512191800), None]);
+		assert_eq!(leaderboard.members[1].times(2017, 1), [Some(200), Some(3700)]);
+
+		let table = leaderboard.to_string();
+		assert_eq!(table.lines().count(), 5);
+		assert!(table.lines().nth(1).unwrap().ends_with("1234567890123456789012345"));
+
+		let day = leaderboard.day(1).to_string();
+		let lines: Vec<&str> = day.lines().collect();
+		assert_eq!(lines.len(), 4);
+		assert!(lines[2].starts_with("   1  Alice"));
+		assert!(lines[2].ends_with("00:05:00       00:10:00       00:05:00"));
+		assert!(lines[3].ends_with("00:03:20       01:01:40       00:58:20"));
+		assert!(leaderboard.day(3).to_string().ends_with("no star yet"));
+		assert_eq!(elapsed(90061), "1d 01:01:01");
+	}
+
+	#[test]
+	fn cache() {
+		let dir = TempDir::new("leaderboard");
+		let path = dir.join("2017/leaderboard-1.json");
+		assert_eq!(fresh(&path), None);
+
+		let (url, server) = serve(vec![(200, JSON.to_string()), (200, "<html></html>".to_string())]);
+		let client = AocClient::new("abc").unwrap().with_base_url(&url);
+		assert_eq!(download(&client, &path, 2017, 1).unwrap(), JSON);
+		assert_eq!(fresh(&path).as_deref(), Some(JSON));
+		assert!(matches!(download(&client, &dir.join("other.json"), 2017, 2), Err(Error::Client(_))));
+		let Err(Error::Client(retry)) = download(&client, &dir.join("other.json"), 2017, 2) else {
+			panic!("a failed download is tried again right away");
+		};
+		assert!(retry.contains("try again in 15 minutes"));
+
+		let requests = server.join().unwrap();
+		assert_eq!(requests.len(), 2);
+		assert_eq!(requests[0].0, "GET /2017/leaderboard/private/view/1.json HTTP/1.1");
+
+		// The cached leaderboard is a file, not a folder
+		let blocked = dir.join("2017/leaderboard-1.json/x.json");
+		assert!(matches!(download(&client, &blocked, 2017, 1), Err(Error::Io(..))));
+	}
+}
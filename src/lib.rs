@@ -1,5 +1,27 @@
+use std::time::Duration;
+
+use colored::Colorize;
+
+pub mod answer;
+pub mod bench;
+mod calendar;
+pub mod client;
 mod collections;
+mod error;
+mod examples;
+pub mod format;
+pub mod history;
+pub mod leaderboard;
+pub mod parse;
+pub mod puzzle;
+mod report;
+pub mod runner;
+mod scaffold;
+pub mod session;
+pub mod solution;
+pub mod submit;
 mod utils;
+pub mod verify;
 
 mod y2015;
 mod y2016;
@@ -12,79 +34,589 @@ mod y2023;
 mod y2024;
 mod y2025;
 
-#[macro_export]
-macro_rules! run_day {
-	($year:path, $day:path) => {{
-		use $crate::utils::colored_time;
-		use $day::{parse_input, part1, part2};
-
-		// Read and parse input
-		let content = $crate::utils::get_input(
-			$crate::utils::extract_integer(stringify!($year)).unwrap(),
-			$crate::utils::extract_integer(stringify!($day)).unwrap(),
-		);
-
-		let before = std::time::Instant::now();
-		let input = parse_input(&content);
-		let parse_time = before.elapsed();
-
-		// Running part 1
-		let before = std::time::Instant::now();
-		let part1_out = part1(&input);
-		let part1_time = before.elapsed();
-
-		// Running part 2
-		let before = std::time::Instant::now();
-		let part2_out = part2(&input);
-		let part2_time = before.elapsed();
-
-		println!(
-			"{}: parse ({}), part1 = {} ({}), part2 = {} ({})",
-			stringify!($day),
-			colored_time(parse_time),
-			part1_out,
-			colored_time(part1_time),
-			part2_out,
-			colored_time(part2_time),
-		);
-	}};
-}
-
-pub fn run(year: u16, day: u8) {
-	match year {
-		2015 => y2015::run(day),
-		2016 => y2016::run(day),
-		2017 => y2017::run(day),
-		2019 => y2019::run(day),
-		2020 => y2020::run(day),
-		2021 => y2021::run(day),
-		2022 => y2022::run(day),
-		2023 => y2023::run(day),
-		2024 => y2024::run(day),
-		2025 => y2025::run(day),
-		_ => unimplemented!(),
-	}
-}
-
-pub fn run_all_year(year: u16) {
-	match year {
-		2015 => y2015::run_all(),
-		2016 => y2016::run_all(),
-		2017 => y2017::run_all(),
-		2019 => y2019::run_all(),
-		2020 => y2020::run_all(),
-		2021 => y2021::run_all(),
-		2022 => y2022::run_all(),
-		2023 => y2023::run_all(),
-		2024 => y2024::run_all(),
-		2025 => y2025::run_all(),
-		_ => unimplemented!(),
-	}
-}
-
-pub fn run_all() {
-	for year in [2015, 2016, 2017, 2019, 2020, 2021, 2022, 2023, 2024, 2025] {
-		println!("YEAR {year}:");
-		run_all_year(year);
+pub use answer::Answer;
+pub use bench::BenchOptions;
+pub use error::Error;
+pub use format::Format;
+pub use history::HistoryOptions;
+pub use parse::ParseError;
+pub use puzzle::Style;
+pub use runner::{DayResult, PartResult, solve};
+pub use scaffold::Template;
+pub use solution::Solution;
+pub use submit::Outcome;
+pub use utils::{cached_input, current_event_year};
+pub use verify::Summary;
+
+use bench::{Total, bench_day};
+use calendar::Calendar;
+use examples::{EXAMPLES_DIR, Example};
+use history::History;
+use report::Report;
+use runner::{Task, solve_in_order};
+use submit::{GUESSES_DIR, Guesses};
+use verify::{AnswerStore, Verdict, Verified};
+
+const YEARS: &[&[&dyn Solution]] = &[
+	y2015::SOLUTIONS,
+	y2016::SOLUTIONS,
+	y2017::SOLUTIONS,
+	y2019::SOLUTIONS,
+	y2020::SOLUTIONS,
+	y2021::SOLUTIONS,
+	y2022::SOLUTIONS,
+	y2023::SOLUTIONS,
+	y2024::SOLUTIONS,
+	y2025::SOLUTIONS,
+];
+
+/// Every registered solution, sorted by year then day.
+pub fn solutions() -> impl Iterator<Item = &'static dyn Solution> {
+	YEARS.iter().flat_map(|year| year.iter().copied())
+}
+
+/// Every year having at least one solution.
+pub fn years() -> impl Iterator<Item = u16> {
+	YEARS.iter().filter_map(|year| year.first()).map(|s| s.year())
+}
+
+pub fn year_solutions(year: u16) -> Result<&'static [&'static dyn Solution], Error> {
+	YEARS
+		.iter()
+		.find(|solutions| solutions.first().is_some_and(|s| s.year() == year))
+		.copied()
+		.ok_or(Error::UnknownYear(year))
+}
+
+pub fn find(year: u16, day: u8) -> Result<&'static dyn Solution, Error> {
+	year_solutions(year)?
+		.iter()
+		.find(|s| s.day() == day)
+		.copied()
+		.ok_or(Error::UnknownDay(year, day))
+}
+
+#[derive(Debug, Clone)]
+pub struct Options {
+	/// Record answers of parts that are not known yet
+	pub record: bool,
+	pub history: HistoryOptions,
+	/// Amount of days solved at the same time
+	pub jobs: usize,
+	/// Time limit of each step of a day
+	pub timeout: Option<Duration>,
+	pub format: Format,
+	/// Only print days with a wrong or failed part
+	pub quiet: bool,
+	/// Only solve this part, both when missing
+	pub part: Option<u8>,
+	/// Path of an input to use instead of the cached ones, `-` for the standard input.
+	/// Answers are not verified against it, and its timings are not saved.
+	pub input: Option<String>,
+	/// Solve the examples instead of the inputs, verifying their expected answers
+	pub example: bool,
+}
+
+impl Options {
+	/// Solving the cached inputs, whose answers are known and whose timings are saved
+	fn real_inputs(&self) -> bool {
+		self.input.is_none() && !self.example
+	}
+}
+
+impl Default for Options {
+	fn default() -> Self {
+		Self {
+			record: false,
+			history: HistoryOptions::default(),
+			jobs: 1,
+			timeout: None,
+			format: Format::Text,
+			quiet: false,
+			part: None,
+			input: None,
+			example: false,
+		}
+	}
+}
+
+const HISTORY_FILE: &str = "./bench_history.jsonl";
+
+/// Read the custom input at `path`, if there's one
+fn custom_input(path: Option<&str>) -> Result<Option<String>, Error> {
+	path.map(|path| utils::read_input_file(path).map_err(|e| Error::Input(path.to_string(), e.to_string())))
+		.transpose()
+}
+
+fn finish_history(history: &History, format: Format) {
+	if let Err(e) = history.save() {
+		eprintln!("warning: unable to save timings history: {e}");
+	}
+
+	if let Some((baseline, regressions)) = history.regressions() {
+		if format == Format::Text {
+			println!("timings: {regressions} steps slower than {baseline}");
+		} else {
+			eprintln!("timings: {regressions} steps slower than {baseline}");
+		}
+	}
+}
+
+/// Read the known answers of a year
+fn load_answers(answers: &mut AnswerStore, year: u16) -> Result<(), Error> {
+	answers
+		.load(year)
+		.map_err(|e| Error::Io(answers.path(year).display().to_string(), e.to_string()))
+}
+
+/// State shared by every day of a run
+struct Run<'a> {
+	options: &'a Options,
+	answers: AnswerStore,
+	history: History,
+	summary: Summary,
+	report: Report,
+	/// Records of the JSON output, printed at the end of the run
+	records: Vec<serde_json::Value>,
+}
+
+impl<'a> Run<'a> {
+	fn new(options: &'a Options) -> Self {
+		let history = HistoryOptions {
+			save: options.history.save && options.real_inputs(),
+			jobs: options.jobs,
+			..options.history.clone()
+		};
+
+		Self {
+			options,
+			answers: AnswerStore::default(),
+			history: History::open(HISTORY_FILE, "run", &history),
+			summary: Summary::default(),
+			report: Report::default(),
+			records: Vec::new(),
+		}
+	}
+
+	fn report(&mut self, result: &DayResult, example: Option<&Example>) {
+		let verdicts = match example {
+			Some(example) => example.verify(result),
+			// Known answers are the ones of the cached inputs
+			None if self.options.input.is_some() => [Verdict::Unknown, Verdict::Unknown],
+			None => self.answers.verify_day(result),
+		};
+		let comparison = self.history.add(result.year, result.day, result.timings());
+
+		match self.options.format {
+			Format::Text if self.options.quiet => {
+				if verdicts.iter().any(|v| matches!(v, Verdict::Wrong { .. })) || result.has_panicked() || result.has_timed_out() {
+					println!("{} d{:02}: {}", result.year, result.day, Verified(result, &verdicts));
+				}
+			}
+			Format::Text => {
+				match example {
+					Some(example) => println!("{} (example {})", Verified(result, &verdicts), example.index),
+					None => println!("{}", Verified(result, &verdicts)),
+				}
+				if let Some(comparison) = comparison {
+					println!("{comparison}");
+				}
+			}
+			Format::Json => self.records.push(format::json_record(result, &verdicts)),
+			Format::Csv => println!("{}", format::csv_record(result, &verdicts)),
+		}
+
+		if self.options.record && self.options.real_inputs() {
+			for (part, (verdict, p)) in verdicts.iter().zip(result.parts()).enumerate() {
+				if let (Verdict::Unknown, Ok(answer)) = (verdict, &p.answer) {
+					self.answers.record(result.year, result.day, part as u8 + 1, answer);
+				}
+			}
+		}
+
+		self.summary.add(result, &verdicts);
+		self.report.add(result);
+	}
+
+	/// Solve every selected day, printing the name of each year when there's several of them
+	fn run(mut self, selected: &[&'static [&'static dyn Solution]]) -> Result<Summary, Error> {
+		let (tasks, examples) = if self.options.example {
+			examples(selected, self.options.part)?
+		} else {
+			for solutions in selected {
+				load_answers(&mut self.answers, solutions[0].year())?;
+			}
+
+			// Inputs are fetched beforehand, as it could ask the session to the user
+			let custom = custom_input(self.options.input.as_deref())?;
+			// A day whose input is unavailable fails, without stopping the others
+			let tasks = selected
+				.iter()
+				.flat_map(|solutions| solutions.iter())
+				.map(|s| Task {
+					solution: *s,
+					input: match &custom {
+						Some(custom) => Ok(custom.clone()),
+						None => utils::get_input(s.year(), s.day()).map_err(|e| e.to_string()),
+					},
+					part: self.options.part,
+				})
+				.collect();
+			(tasks, Vec::new())
+		};
+
+		if self.options.format == Format::Csv {
+			println!("{}", format::CSV_HEADER);
+		}
+
+		let text = self.options.format == Format::Text;
+		let mut year = None;
+		let mut examples = examples.iter();
+		solve_in_order(&tasks, self.options.jobs, self.options.timeout, |result| {
+			if text && !self.options.quiet && selected.len() > 1 && year != Some(result.year) {
+				println!("YEAR {}:", result.year);
+				year = Some(result.year);
+			}
+			self.report(&result, examples.next());
+		});
+
+		match self.options.format {
+			Format::Text if tasks.len() > 1 && !self.options.quiet => println!("\n{}\n", self.report),
+			Format::Json => println!("{}", serde_json::to_string_pretty(&self.records).unwrap()),
+			_ => (),
+		}
+
+		if self.options.record {
+			self.answers
+				.save()
+				.map_err(|e| Error::Io(self.answers.dir().display().to_string(), e.to_string()))?;
+		}
+		finish_history(&self.history, self.options.format);
+
+		Ok(self.summary)
+	}
+}
+
+/// Examples of the selected days along with their tasks, `part` overrides the parts the examples apply to
+fn examples(selected: &[&'static [&'static dyn Solution]], part: Option<u8>) -> Result<(Vec<Task>, Vec<Example>), Error> {
+	let (mut tasks, mut examples) = (Vec::new(), Vec::new());
+	for solutions in selected {
+		let year = examples::load(std::path::Path::new(EXAMPLES_DIR), solutions[0].year())
+			.map_err(|e| Error::Input(EXAMPLES_DIR.to_string(), e.to_string()))?;
+		for example in year {
+			if let Some(solution) = solutions.iter().find(|s| s.day() == example.day) {
+				tasks.push(Task {
+					solution: *solution,
+					input: Ok(example.input.clone()),
+					part: part.or(example.part()),
+				});
+				examples.push(example);
+			}
+		}
+	}
+
+	if examples.is_empty() {
+		eprintln!("warning: no examples of the selected days in {EXAMPLES_DIR}");
+	}
+
+	Ok((tasks, examples))
+}
+
+pub fn run(year: u16, day: u8, options: &Options) -> Result<Summary, Error> {
+	Run::new(options).run(&select(Some(year), Some(day))?)
+}
+
+pub fn run_all_year(year: u16, options: &Options) -> Result<Summary, Error> {
+	Run::new(options).run(&select(Some(year), None)?)
+}
+
+pub fn run_all(options: &Options) -> Result<Summary, Error> {
+	Run::new(options).run(&select(None, None)?)
+}
+
+/// Solutions grouped by year, selected by an optional year and an optional day
+fn select(year: Option<u16>, day: Option<u8>) -> Result<Vec<&'static [&'static dyn Solution]>, Error> {
+	match (year, day) {
+		(Some(y), Some(d)) => {
+			let solutions = year_solutions(y)?;
+			let index = solutions.iter().position(|s| s.day() == d).ok_or(Error::UnknownDay(y, d))?;
+			Ok(vec![&solutions[index..=index]])
+		}
+		(Some(y), None) => Ok(vec![year_solutions(y)?]),
+		(None, _) => Ok(YEARS.iter().filter(|solutions| !solutions.is_empty()).copied().collect()),
+	}
+}
+
+pub fn bench(year: Option<u16>, day: Option<u8>, options: &BenchOptions) -> Result<(), Error> {
+	let selected = select(year, day)?;
+	let custom = custom_input(options.input.as_deref())?;
+	let history = HistoryOptions {
+		save: options.history.save && custom.is_none(),
+		..options.history.clone()
+	};
+	let mut history = History::open(HISTORY_FILE, "bench", &history);
+	let mut failure = None;
+	for solutions in &selected {
+		if selected.len() > 1 {
+			println!("YEAR {}:", solutions[0].year());
+		}
+
+		let mut total = Total::default();
+		for solution in solutions.iter() {
+			let content = match &custom {
+				Some(custom) => custom.clone(),
+				None => match utils::get_input(solution.year(), solution.day()) {
+					Ok(input) => input,
+					// The other days are still measured, the first failure is returned at the end
+					Err(e) => {
+						println!("d{:02}: {}", solution.day(), e.to_string().red());
+						failure.get_or_insert(e);
+						continue;
+					}
+				},
+			};
+			let res = bench_day(*solution, &content, options);
+			println!("{res}");
+			total.add(&res);
+
+			// The statistics of a day stopped by the time limit are not comparable
+			if !res.timed_out
+				&& let Some(comparison) = history.add(res.year, res.day, res.timings())
+			{
+				println!("{comparison}");
+			}
+		}
+		println!("{total}");
+	}
+	finish_history(&history, Format::Text);
+
+	failure.map_or(Ok(()), Err)
+}
+
+/// Download the inputs of a day, or of every solved day of a year
+pub fn fetch(year: u16, day: Option<u8>, force: bool) -> Result<(), Error> {
+	let days: Vec<u8> = match day {
+		Some(d) if (1..=utils::event_days(year)).contains(&d) => vec![d],
+		Some(d) => return Err(Error::UnknownDay(year, d)),
+		None => year_solutions(year)?.iter().map(|s| s.day()).collect(),
+	};
+
+	let cached = |path: String| !force && std::path::Path::new(&path).is_file();
+	for day in days {
+		if cached(utils::input_path(year, day)) {
+			println!("{year} d{day:02}: already cached");
+		} else {
+			let input = utils::fetch_input(year, day)?;
+			println!("{year} d{day:02}: downloaded {} bytes", input.len());
+		}
+
+		// The input is enough to solve the day
+		if !cached(utils::puzzle_path(year, day)) {
+			match utils::fetch_puzzle(year, day) {
+				Ok(_) => println!("{year} d{day:02}: downloaded the description"),
+				Err(e) => eprintln!("warning: {e}"),
+			}
+		}
+	}
+
+	Ok(())
+}
+
+/// Page of a puzzle description.
+/// It's downloaded when it's missing, or to get the second part when the first one is solved and the answers changed since
+/// it was cached: it's not downloaded again while the second part stays locked, like the last day without the other stars.
+/// The cached page is kept when it can't be downloaded again.
+fn puzzle_page(year: u16, day: u8, force: bool) -> Result<String, Error> {
+	if !(1..=utils::event_days(year)).contains(&day) {
+		return Err(Error::UnknownDay(year, day));
+	}
+
+	let mut answers = AnswerStore::default();
+	load_answers(&mut answers, year)?;
+	let modified = |path: &std::path::Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+	let solved_since = answers.get(year, day, 1).is_some()
+		&& modified(std::path::Path::new(&utils::puzzle_path(year, day))) < modified(&answers.path(year));
+	match utils::cached_puzzle(year, day) {
+		Some(page) if !force && (!solved_since || puzzle::articles(&page).len() > 1) => Ok(page),
+		Some(page) => utils::fetch_puzzle(year, day).or_else(|e| {
+			eprintln!("warning: {e}, using the cached description");
+			Ok(page)
+		}),
+		None => utils::fetch_puzzle(year, day),
+	}
+}
+
+/// Print the description of a puzzle
+pub fn read(year: u16, day: u8, style: Style, force: bool) -> Result<(), Error> {
+	let page = puzzle_page(year, day, force)?;
+
+	let parts: Vec<String> = puzzle::articles(&page).iter().map(|a| puzzle::render(a, style)).collect();
+	println!("{}", parts.join("\n\n"));
+
+	Ok(())
+}
+
+/// Check that the session of the selected profile is valid, printing the user it's logged in as
+pub fn check_session() -> Result<(), Error> {
+	let profile = session::profile();
+	let found = session::session().map_err(|e| Error::Session(e.to_string()))?;
+	println!("profile {profile}: session from {}", found.source);
+
+	match utils::logged_user(&found.token)? {
+		Some(user) => {
+			println!("logged in as {user}");
+			Ok(())
+		}
+		None => Err(Error::Session("it is not logged in, it may have expired".to_string())),
+	}
+}
+
+/// Store the session of the selected profile in the configuration of the user, read from the standard input
+pub fn set_session() -> Result<(), Error> {
+	let profile = session::profile();
+	let config = session::config_file().ok_or_else(|| Error::Session("no configuration folder".to_string()))?;
+	let token = session::read_token(&profile).map_err(|e| Error::Session(e.to_string()))?;
+	session::store_session(&config, &profile, &token).map_err(|e| Error::Session(e.to_string()))?;
+	println!("profile {profile}: session stored in {}", config.display());
+
+	Ok(())
+}
+
+/// Answer of a part given by its solution, which fails when it takes more than `timeout`
+fn solved_answer(year: u16, day: u8, part: u8, timeout: Option<Duration>) -> Result<String, Error> {
+	let input = utils::get_input(year, day)?;
+	let result = runner::solve_with_limit(find(year, day)?, &input, Some(part), timeout);
+
+	match &result.parts()[part as usize - 1].answer {
+		Ok(Answer::Image(image)) => Err(Error::Refused(format!("the answer needs to be read and given:\n{image}"))),
+		Ok(answer) => answer.value().ok_or_else(|| Error::Refused("the part has no answer".to_string())),
+		Err(failure) => Err(Error::Refused(format!("the part failed, {failure}"))),
+	}
+}
+
+/// Send the answer of a part, the one of its solution when it's missing, and print the outcome.
+/// Answers ruled out by the previous guesses aren't sent, unless `force` is set.
+pub fn submit(year: u16, day: u8, part: u8, answer: Option<&str>, force: bool, timeout: Option<Duration>) -> Result<Outcome, Error> {
+	let answer = match answer {
+		Some(answer) => answer.trim().to_string(),
+		None => solved_answer(year, day, part, timeout)?,
+	};
+
+	let dir = std::path::Path::new(GUESSES_DIR);
+	let mut answers = AnswerStore::default();
+	load_answers(&mut answers, year)?;
+	let mut guesses = Guesses::load(dir, year).map_err(|e| Error::Io(Guesses::file(dir, year).display().to_string(), e.to_string()))?;
+	println!("{year} d{day:02} part {part}: sending {answer}");
+	let outcome = submit::send(utils::client()?, &mut answers, &mut guesses, (year, day, part), &answer, force)?;
+
+	// The answer was sent, its outcome is printed even if it can't be saved
+	let saved = guesses
+		.save()
+		.map_err(|e| Error::Io(guesses.path().display().to_string(), e.to_string()))
+		.and_then(|_| {
+			answers
+				.save()
+				.map_err(|e| Error::Io(answers.dir().display().to_string(), e.to_string()))
+		});
+	println!("{outcome}");
+	saved?;
+
+	Ok(outcome)
+}
+
+/// Create the file of a new day from a template and register it, the next day of the year if `day` is missing.
+/// Its tests use the example of the puzzle description when it's cached.
+pub fn scaffold(year: u16, day: Option<u8>, template: Template) -> Result<(), Error> {
+	let examples = |day| {
+		utils::cached_puzzle(year, day)
+			.map(|page| examples::extract(year, day, &page))
+			.unwrap_or_default()
+	};
+	for path in scaffold::new_day(std::path::Path::new("."), year, day, template, examples)? {
+		println!("wrote {}", path.display());
+	}
+
+	Ok(())
+}
+
+/// Write the examples of a puzzle description to the examples folder, with their expected answers.
+/// The answers are guessed from the description, a note asks to check the written ones.
+pub fn extract_examples(year: u16, day: u8, force: bool) -> Result<(), Error> {
+	let page = puzzle_page(year, day, force)?;
+	let examples = examples::extract(year, day, &page);
+	if examples.is_empty() {
+		println!("{year} d{day:02}: no example found");
+	}
+
+	let mut wrote = false;
+	for example in &examples {
+		let (index, written) =
+			examples::save(std::path::Path::new(EXAMPLES_DIR), example).map_err(|e| Error::Io(EXAMPLES_DIR.to_string(), e.to_string()))?;
+		if written.is_empty() {
+			println!("{year} d{day:02}: example {} is already there as example {index}", example.index);
+		}
+		for path in written {
+			println!("wrote {}", path.display());
+			wrote = true;
+		}
+	}
+	if wrote {
+		println!("the expected answers are the last emphasised values of each part of the description, check them");
+	}
+
+	Ok(())
+}
+
+/// Print the ranking of a private leaderboard, or the times of its members for a day.
+/// It's downloaded at most once every 15 minutes, as asked by the website.
+pub fn leaderboard(id: u64, year: u16, day: Option<u8>) -> Result<(), Error> {
+	if let Some(day) = day.filter(|d| !(1..=utils::event_days(year)).contains(d)) {
+		return Err(Error::UnknownDay(year, day));
+	}
+
+	let path = utils::leaderboard_path(year, id);
+	let path = std::path::Path::new(&path);
+	let json = match leaderboard::fresh(path) {
+		Some(json) => json,
+		None => leaderboard::download(utils::client()?, path, year, id)?,
+	};
+
+	let board = leaderboard::Leaderboard::parse(year, &json).map_err(|e| Error::Client(format!("invalid leaderboard {id}: {e}")))?;
+	match day {
+		Some(day) => println!("{}", board.day(day)),
+		None => println!("{board}"),
+	}
+
+	Ok(())
+}
+
+/// Print a calendar of the solved days of every year, with their cached inputs and known answers
+pub fn list(year: Option<u16>) -> Result<(), Error> {
+	let mut answers = AnswerStore::default();
+	for solutions in select(year, None)? {
+		let year = solutions[0].year();
+		load_answers(&mut answers, year)?;
+		println!("{}\n", Calendar::new(year, solutions, &answers));
+	}
+	println!("{}", calendar::LEGEND);
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn registry_is_sorted() {
+		let keys: Vec<(u16, u8)> = solutions().map(|s| (s.year(), s.day())).collect();
+		assert!(keys.windows(2).all(|w| w[0] < w[1]));
+	}
+
+	#[test]
+	fn find_missing() {
+		assert_eq!(find(2024, 5).map(|s| (s.year(), s.day())), Ok((2024, 5)));
+		assert_eq!(find(2018, 1).err(), Some(Error::UnknownYear(2018)));
+		assert_eq!(find(2025, 13).err(), Some(Error::UnknownDay(2025, 13)));
 	}
 }
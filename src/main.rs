@@ -1,27 +1,445 @@
-use clap::Parser;
+use std::process::ExitCode;
+use std::time::Duration;
 
-use advent_of_code::{run, run_all, run_all_year};
+use clap::{Args, Parser, Subcommand};
+
+use advent_of_code::{
+	BenchOptions, Error, Format, HistoryOptions, Options, Outcome, Style, Summary, Template, bench, check_session, current_event_year,
+	extract_examples, fetch, leaderboard, list, read, run, run_all, run_all_year, scaffold, set_session, submit,
+};
 
 #[derive(Parser)]
-struct Args {
+struct Cli {
+	/// Solve every day of every year when missing
+	#[clap(subcommand)]
+	command: Option<Command>,
+
+	/// Session profile to use, AOC_PROFILE or `default` when missing
+	#[clap(long, global = true)]
+	profile: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Solve days and verify their answers
+	Run(RunArgs),
+	/// Run days several times and report timing statistics
+	Bench(BenchArgs),
+	/// Download inputs
+	Fetch(FetchArgs),
+	/// Send an answer
+	Submit(SubmitArgs),
+	/// Create the file of a new day and register it
+	New(NewArgs),
+	/// Show a calendar of the solved days, their inputs and known answers
+	List(ListArgs),
+	/// Show the description of a puzzle, downloaded once
+	Read(ReadArgs),
+	/// Write the examples of a puzzle description to ./data/examples, with their expected answers
+	Extract(ExtractArgs),
+	/// Show the ranking of a private leaderboard, or the times of its members for a day
+	Leaderboard(LeaderboardArgs),
+	/// Solve days and only report wrong or failed answers
+	Test(TestArgs),
+	/// Manage the session token, read from AOC_SESSION, the configuration of the user or a prompt
+	#[clap(subcommand)]
+	Session(SessionCommand),
+}
+
+#[derive(Subcommand)]
+enum SessionCommand {
+	/// Check that the session of the profile is logged in
+	Check,
+	/// Store the session of the profile, read from a prompt or the standard input
+	Set,
+}
+
+/// Days to work on
+#[derive(Args)]
+struct Selection {
+	/// Year of the days, the last event when only a day is given
+	#[clap(short, long)]
+	year: Option<u16>,
+
+	/// Day of the year, every day when missing
+	#[clap(short, long)]
+	day: Option<u8>,
+}
+
+impl Selection {
+	fn year(&self) -> Option<u16> {
+		self.year.or(self.day.map(|_| current_event_year()))
+	}
+}
+
+/// Parts to solve and their input
+#[derive(Args)]
+struct Target {
+	/// Only solve this part
+	#[clap(short, long, possible_values = ["1", "2"])]
+	part: Option<u8>,
+
+	/// Path of the input to use instead of the cached one, `-` to read it from stdin
+	#[clap(short, long, requires = "day")]
+	input: Option<String>,
+}
+
+#[derive(Args)]
+struct HistoryArgs {
+	/// Label of the saved timings, instead of the git revision
+	#[clap(long)]
+	label: Option<String>,
+
+	/// Label of saved timings to compare with
+	#[clap(long)]
+	compare: Option<String>,
+
+	/// Percentage of slowdown flagged as a regression, when comparing
+	#[clap(long, default_value_t = 10.0)]
+	threshold: f64,
+}
+
+impl HistoryArgs {
+	fn options(&self) -> HistoryOptions {
+		HistoryOptions {
+			label: self.label.clone(),
+			compare: self.compare.clone(),
+			threshold: self.threshold,
+			..Default::default()
+		}
+	}
+}
+
+/// Check that a duration in seconds is a positive number which can be represented
+fn seconds(value: &str) -> Result<(), String> {
+	let secs: f64 = value.parse().map_err(|_| format!("{value} is not a number of seconds"))?;
+	if secs < 0.0 || Duration::try_from_secs_f64(secs).is_err() {
+		return Err(format!("{value} is not a positive and finite number of seconds"));
+	}
+	Ok(())
+}
+
+#[derive(Args)]
+struct Limit {
+	/// Time limit in seconds of each part, 0 to disable it.
+	/// A part exceeding it keeps running in the background until the end of the program.
+	#[clap(long, default_value_t = 30.0, validator = seconds)]
+	timeout: f64,
+}
+
+impl Limit {
+	fn duration(&self) -> Option<Duration> {
+		(self.timeout > 0.0).then(|| Duration::from_secs_f64(self.timeout))
+	}
+}
+
+#[derive(Args)]
+struct Execution {
+	/// Amount of days solved at the same time, defaults to the amount of CPUs
+	#[clap(short, long)]
+	jobs: Option<usize>,
+
+	/// Solve days one after the other, for accurate timings
+	#[clap(long, conflicts_with = "jobs")]
+	serial: bool,
+
+	#[clap(flatten)]
+	limit: Limit,
+}
+
+impl Execution {
+	fn options(&self, target: &Target) -> Options {
+		let jobs = if self.serial {
+			1
+		} else {
+			self.jobs
+				.unwrap_or_else(|| std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1))
+		};
+
+		Options {
+			jobs,
+			timeout: self.limit.duration(),
+			part: target.part,
+			input: target.input.clone(),
+			..Default::default()
+		}
+	}
+}
+
+#[derive(Args)]
+struct RunArgs {
+	#[clap(flatten)]
+	selection: Selection,
+
+	#[clap(flatten)]
+	target: Target,
+
+	#[clap(flatten)]
+	execution: Execution,
+
+	#[clap(flatten)]
+	history: HistoryArgs,
+
+	/// Record answers that are not known yet as the correct ones
+	#[clap(long)]
+	record: bool,
+
+	/// Output format: text, json or csv
+	#[clap(long, default_value = "text")]
+	format: Format,
+
+	/// Solve the examples of ./data/examples instead of the inputs, checking their expected answers
+	#[clap(long, conflicts_with = "input")]
+	example: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+	#[clap(flatten)]
+	selection: Selection,
+
+	#[clap(flatten)]
+	target: Target,
+
+	#[clap(flatten)]
+	history: HistoryArgs,
+
+	#[clap(flatten)]
+	limit: Limit,
+
+	/// Runs done before measuring
+	#[clap(long, default_value_t = 1)]
+	warmup: usize,
+
+	/// Maximum amount of measured runs per day
+	#[clap(long, default_value_t = 100)]
+	runs: usize,
+
+	/// Maximum time in seconds spent measuring a day
+	#[clap(long, default_value_t = 5.0, validator = seconds)]
+	budget: f64,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+	#[clap(flatten)]
+	selection: Selection,
+
+	/// Download inputs even if they are already cached
+	#[clap(long)]
+	force: bool,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+	year: u16,
+	day: u8,
+	#[clap(possible_values = ["1", "2"])]
+	part: u8,
+
+	/// The answer of the solution when missing
+	answer: Option<String>,
+
+	/// Send the answer even if a previous guess rules it out
+	#[clap(long)]
+	force: bool,
+
+	#[clap(flatten)]
+	limit: Limit,
+}
+
+#[derive(Args)]
+struct NewArgs {
+	year: u16,
+
+	/// The day after the last one of the year when missing
+	day: Option<u8>,
+
+	/// Skeleton of the day: lines, grid, intcode, regex-records or graph
+	#[clap(short, long, default_value = "lines")]
+	template: Template,
+}
+
+#[derive(Args)]
+struct ListArgs {
+	year: Option<u16>,
+}
+
+#[derive(Args)]
+struct LeaderboardArgs {
+	/// Number of the leaderboard, in its URL
+	id: u64,
+
+	/// The last event when missing
 	#[clap(short, long)]
 	year: Option<u16>,
 
+	/// Show the times of each star of this day
 	#[clap(short, long)]
 	day: Option<u8>,
 }
 
-fn main() {
-	let args = Args::parse();
+#[derive(Args)]
+struct ExtractArgs {
+	year: u16,
+	day: u8,
+
+	/// Download the description even if it is already cached
+	#[clap(long)]
+	force: bool,
+}
 
-	match (args.year, args.day) {
+#[derive(Args)]
+struct ReadArgs {
+	year: u16,
+	day: u8,
+
+	/// Print markdown instead of colored text
+	#[clap(long)]
+	markdown: bool,
+
+	/// Download the description even if it is already cached
+	#[clap(long)]
+	force: bool,
+}
+
+#[derive(Args)]
+struct TestArgs {
+	#[clap(flatten)]
+	selection: Selection,
+
+	#[clap(flatten)]
+	target: Target,
+
+	/// Solve the examples of ./data/examples instead of the inputs, checking their expected answers
+	#[clap(long, conflicts_with = "input")]
+	example: bool,
+
+	#[clap(flatten)]
+	execution: Execution,
+}
+
+fn run_selection(selection: &Selection, options: &Options) -> Result<Summary, Error> {
+	match (selection.year(), selection.day) {
 		// Running specific year and day
-		(Some(y), Some(d)) => run(y, d),
+		(Some(y), Some(d)) => run(y, d, options),
 		// Running all days of a year
-		(Some(y), _) => run_all_year(y),
-		// Running a specific day of last year
-		(_, Some(d)) => run(2025, d),
+		(Some(y), _) => run_all_year(y, options),
 		// Running all days of all years
-		(_, _) => run_all(),
+		(_, _) => run_all(options),
+	}
+}
+
+fn exit_code(res: Result<(), impl std::error::Error>) -> ExitCode {
+	match res {
+		Ok(()) => ExitCode::SUCCESS,
+		Err(e) => {
+			eprintln!("error: {e}");
+			ExitCode::FAILURE
+		}
+	}
+}
+
+fn summary_code(summary: Summary, format: Format) -> ExitCode {
+	// Keep the standard output parsable by other tools
+	if format == Format::Text {
+		println!("{summary}");
+	} else {
+		eprintln!("{summary}");
+	}
+
+	if summary.is_success() {
+		ExitCode::SUCCESS
+	} else {
+		ExitCode::FAILURE
+	}
+}
+
+fn main() -> ExitCode {
+	let cli = Cli::parse();
+	advent_of_code::runner::quiet_panics();
+	if let Some(profile) = &cli.profile {
+		advent_of_code::session::select_profile(profile);
+	}
+
+	// Running every day by default, with the default options of `run`
+	let command = match cli.command {
+		Some(command) => command,
+		None => Cli::parse_from(["advent-of-code", "run"]).command.unwrap(),
+	};
+
+	match command {
+		Command::Run(args) => {
+			let options = Options {
+				record: args.record,
+				history: args.history.options(),
+				format: args.format,
+				example: args.example,
+				..args.execution.options(&args.target)
+			};
+
+			match run_selection(&args.selection, &options) {
+				Ok(summary) => summary_code(summary, options.format),
+				Err(e) => exit_code(Err(e)),
+			}
+		}
+		Command::Test(args) => {
+			let options = Options {
+				quiet: true,
+				example: args.example,
+				history: HistoryOptions {
+					save: false,
+					..Default::default()
+				},
+				..args.execution.options(&args.target)
+			};
+
+			match run_selection(&args.selection, &options) {
+				Ok(summary) => summary_code(summary, options.format),
+				Err(e) => exit_code(Err(e)),
+			}
+		}
+		Command::Bench(args) => {
+			let options = BenchOptions {
+				warmup: args.warmup,
+				runs: args.runs,
+				budget: Duration::from_secs_f64(args.budget),
+				part: args.target.part,
+				timeout: args.limit.duration(),
+				input: args.target.input,
+				history: args.history.options(),
+			};
+
+			exit_code(bench(args.selection.year(), args.selection.day, &options))
+		}
+		Command::Fetch(args) => {
+			let year = args.selection.year().unwrap_or_else(current_event_year);
+			exit_code(fetch(year, args.selection.day, args.force))
+		}
+		Command::Submit(args) => match submit(
+			args.year,
+			args.day,
+			args.part,
+			args.answer.as_deref(),
+			args.force,
+			args.limit.duration(),
+		) {
+			Ok(Outcome::Correct) => ExitCode::SUCCESS,
+			Ok(_) => ExitCode::FAILURE,
+			Err(e) => exit_code(Err(e)),
+		},
+		Command::New(args) => exit_code(scaffold(args.year, args.day, args.template)),
+		Command::List(args) => exit_code(list(args.year)),
+		Command::Leaderboard(args) => {
+			let year = args.year.unwrap_or_else(current_event_year);
+			exit_code(leaderboard(args.id, year, args.day))
+		}
+		Command::Extract(args) => exit_code(extract_examples(args.year, args.day, args.force)),
+		Command::Read(args) => {
+			let style = if args.markdown { Style::Markdown } else { Style::Terminal };
+			exit_code(read(args.year, args.day, style, args.force))
+		}
+		Command::Session(SessionCommand::Check) => exit_code(check_session()),
+		Command::Session(SessionCommand::Set) => exit_code(set_session()),
 	}
 }
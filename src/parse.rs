@@ -0,0 +1,97 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Invalid input of a day, pointing at the offending text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+	/// Day of the solution, set when it's registered
+	pub year: u16,
+	pub day: u8,
+	/// Position of the offending text, starting from 1
+	pub line: usize,
+	pub column: usize,
+	pub text: String,
+	/// Whole line of the offending text
+	pub context: String,
+	pub message: String,
+}
+
+impl ParseError {
+	/// Error about `text`, which should be a slice of `input`.
+	/// Otherwise, its first occurrence in `input` is reported.
+	pub fn new(input: &str, text: &str, message: impl Into<String>) -> Self {
+		let offset = (text.as_ptr() as usize)
+			.checked_sub(input.as_ptr() as usize)
+			.filter(|offset| input.get(*offset..).is_some_and(|rest| rest.starts_with(text)))
+			.or_else(|| input.find(text))
+			.unwrap_or(0);
+
+		let before = &input[..offset];
+		let start = before.rfind('\n').map_or(0, |i| i + 1);
+		let end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+
+		Self {
+			year: 0,
+			day: 0,
+			line: before.matches('\n').count() + 1,
+			column: before[start..].chars().count() + 1,
+			text: text.to_string(),
+			context: input[start..end].to_string(),
+			message: message.into(),
+		}
+	}
+
+	/// Set the day of the solution which failed to parse
+	pub fn of(self, year: u16, day: u8) -> Self {
+		Self { year, day, ..self }
+	}
+}
+
+impl Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "invalid input")?;
+		if self.year > 0 {
+			write!(f, " of {} day {:02}", self.year, self.day)?;
+		}
+		writeln!(f, " at line {}, column {}: {}", self.line, self.column, self.message)?;
+
+		let number = self.line.to_string();
+		let padding = " ".repeat(number.len());
+		let marker = "^".repeat(self.text.chars().count().max(1));
+		writeln!(f, " {number} | {}", self.context)?;
+		write!(f, " {padding} | {}{marker}", " ".repeat(self.column - 1))
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse `text`, a slice of `input`, reporting its position if it's invalid
+pub fn parse_at<T>(input: &str, text: &str) -> Result<T, ParseError>
+where
+	T: FromStr,
+	T::Err: Display,
+{
+	text.parse().map_err(|e| ParseError::new(input, text, format!("{e} ({text:?})")))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn position() {
+		let input = "L68\nR3x0\nL5";
+		let line = input.lines().nth(1).unwrap();
+
+		let e = parse_at::<u32>(input, &line[1..]).unwrap_err();
+		assert_eq!((e.line, e.column), (2, 2));
+		assert_eq!((e.text.as_str(), e.context.as_str()), ("3x0", "R3x0"));
+
+		let e = ParseError::new(input, "L5", "unknown").of(2025, 1);
+		assert_eq!((e.line, e.column), (3, 1));
+		assert_eq!(
+			e.to_string(),
+			"invalid input of 2025 day 01 at line 3, column 1: unknown\n 3 | L5\n   | ^^"
+		);
+	}
+}
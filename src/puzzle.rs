@@ -0,0 +1,308 @@
+use colored::Colorize;
+use regex::Regex;
+
+use crate::client::BASE_URL;
+
+/// How to render a puzzle description
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+	Markdown,
+	/// Text with colors, like the emphasised words of the website
+	Terminal,
+}
+
+/// Inner HTML of each `<article>` of a puzzle page, one for each unlocked part
+pub fn articles(page: &str) -> Vec<&str> {
+	let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+	article.captures_iter(page).map(|c| c.get(1).unwrap().as_str()).collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+	/// Name and attributes of an opening tag
+	Open(&'a str, &'a str),
+	Close(&'a str),
+	Text(&'a str),
+}
+
+fn tokens(html: &str) -> Vec<Token<'_>> {
+	let tag = Regex::new(r"<(/?)([a-zA-Z0-9]+)([^>]*)>").unwrap();
+
+	let mut tokens = Vec::new();
+	let mut last = 0;
+	for c in tag.captures_iter(html) {
+		let m = c.get(0).unwrap();
+		if m.start() > last {
+			tokens.push(Token::Text(&html[last..m.start()]));
+		}
+		let name = c.get(2).unwrap().as_str();
+		if c[1].is_empty() {
+			tokens.push(Token::Open(name, c.get(3).unwrap().as_str()));
+		} else {
+			tokens.push(Token::Close(name));
+		}
+		last = m.end();
+	}
+	if last < html.len() {
+		tokens.push(Token::Text(&html[last..]));
+	}
+
+	tokens
+}
+
+/// Replace the HTML entities of a text by their characters
+pub fn decode_entities(text: &str) -> String {
+	let entity = Regex::new(r"&(#x[0-9a-fA-F]+|#[0-9]+|[a-z]+);").unwrap();
+	entity
+		.replace_all(text, |c: &regex::Captures| {
+			let name = &c[1];
+			let decoded = match name {
+				"lt" => Some('<'),
+				"gt" => Some('>'),
+				"amp" => Some('&'),
+				"quot" => Some('"'),
+				"apos" => Some('\''),
+				"nbsp" => Some(' '),
+				_ if name.starts_with("#x") => u32::from_str_radix(&name[2..], 16).ok().and_then(char::from_u32),
+				_ if name.starts_with('#') => name[1..].parse().ok().and_then(char::from_u32),
+				_ => None,
+			};
+			decoded.map_or_else(|| c[0].to_string(), String::from)
+		})
+		.to_string()
+}
+
+/// Text of some HTML, without its tags
+pub fn strip_tags(html: &str) -> String {
+	let text: String = tokens(html)
+		.into_iter()
+		.filter_map(|token| match token {
+			Token::Text(text) => Some(text),
+			_ => None,
+		})
+		.collect();
+	decode_entities(&text)
+}
+
+/// Blocks of code of an article, with the HTML preceding each of them since the previous one
+pub fn code_blocks(article: &str) -> Vec<(&str, String)> {
+	let block = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+
+	let mut last = 0;
+	block
+		.captures_iter(article)
+		.map(|c| {
+			let m = c.get(0).unwrap();
+			let before = &article[last..m.start()];
+			last = m.end();
+			(before, strip_tags(&c[1]).trim_end_matches('\n').to_string())
+		})
+		.collect()
+}
+
+/// Emphasised code of an article, where the website shows the answers of examples
+pub fn emphasised_code(article: &str) -> Vec<String> {
+	let code = Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap();
+	code.captures_iter(article)
+		.map(|c| strip_tags(c.get(1).or(c.get(2)).unwrap().as_str()))
+		.collect()
+}
+
+/// Render the HTML of a puzzle description as readable text
+pub fn render(html: &str, style: Style) -> String {
+	let markdown = style == Style::Markdown;
+	let tokens = tokens(html);
+	let href = Regex::new(r#"href="([^"]*)""#).unwrap();
+	let spaces = Regex::new(r"\s+").unwrap();
+
+	let mut out = String::new();
+	let (mut pre, mut code, mut heading) = (false, false, false);
+	// Emphasised code is rendered as an emphasised block of code, instead of emphasis inside of code
+	let mut emphasised_code = false;
+	let mut em = 0;
+	let mut link = None;
+
+	for (i, token) in tokens.iter().enumerate() {
+		match *token {
+			Token::Open("h2", _) => {
+				heading = true;
+				if markdown {
+					out += "## ";
+				}
+			}
+			Token::Close("h2") => {
+				heading = false;
+				out += "\n\n";
+			}
+			Token::Close("p") => out += "\n\n",
+			Token::Open("li", _) => out += "- ",
+			Token::Close("li") => out += "\n",
+			Token::Close("ul") => out += "\n",
+			Token::Open("pre", _) => {
+				pre = true;
+				out += if markdown { "```\n" } else { "    " };
+			}
+			Token::Close("pre") => {
+				pre = false;
+				out.truncate(out.trim_end_matches(' ').len());
+				if !out.ends_with('\n') {
+					out += "\n";
+				}
+				out += if markdown { "```\n\n" } else { "\n" };
+			}
+			Token::Open("code", _) if !pre => {
+				code = true;
+				emphasised_code = matches!(tokens.get(i + 1), Some(Token::Open("em", _)));
+				if markdown {
+					out += if emphasised_code { "**`" } else { "`" };
+				}
+			}
+			Token::Close("code") if !pre => {
+				code = false;
+				if markdown {
+					out += if emphasised_code { "`**" } else { "`" };
+				}
+			}
+			Token::Open("em", _) => {
+				em += 1;
+				if markdown && !code && !pre {
+					out += "**";
+				}
+			}
+			Token::Close("em") => {
+				em -= 1;
+				if markdown && !code && !pre {
+					out += "**";
+				}
+			}
+			Token::Open("a", attributes) => {
+				link = href.captures(attributes).map(|c| {
+					let url = decode_entities(&c[1]);
+					if url.starts_with('/') { format!("{BASE_URL}{url}") } else { url }
+				});
+				if markdown && link.is_some() {
+					out += "[";
+				}
+			}
+			Token::Close("a") => {
+				if let Some(url) = link.take()
+					&& markdown
+				{
+					out += &format!("]({url})");
+				}
+			}
+			Token::Text(text) => {
+				let text = decode_entities(text);
+				let text = if pre {
+					if markdown { text } else { text.replace('\n', "\n    ") }
+				} else {
+					spaces.replace_all(&text, " ").to_string()
+				};
+				// Blocks are separated by newlines between their tags
+				if !pre && text.trim().is_empty() && (out.is_empty() || out.ends_with('\n')) {
+					continue;
+				}
+
+				if markdown {
+					out += &text;
+				} else if heading {
+					out += &text.bold().to_string();
+				} else if em > 0 {
+					out += &text.bold().bright_white().to_string();
+				} else if link.is_some() {
+					out += &text.underline().to_string();
+				} else {
+					out += &text;
+				}
+			}
+			_ => (),
+		}
+	}
+
+	// Lines don't start with the spaces separating the tags of the HTML
+	let blank = Regex::new(r"\n +(\S)").unwrap();
+	let out = if markdown {
+		blank.replace_all(&out, "\n$1").to_string()
+	} else {
+		out
+	};
+	let newlines = Regex::new(r"\n{3,}").unwrap();
+	newlines.replace_all(out.trim(), "\n\n").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const PAGE: &str = include_str!("../tests/fixtures/puzzle.html");
+
+	#[test]
+	fn markdown() {
+		let articles = articles(PAGE);
+		assert_eq!(articles.len(), 2);
+
+		assert_eq!(
+			render(articles[0], Style::Markdown),
+			"## --- Day 1: Lantern Shelves ---
+
+The Elves store their lanterns on shelves & want to know which shelf is the busiest.
+
+Each line of the inventory lists the lanterns of a shelf. For example:
+
+```
+3 4
+1 2
+```
+
+- The first shelf has `3 + 4 = 7` lanterns.
+- The second shelf has `1 + 2 = 3` lanterns.
+
+In this example, the busiest shelf has **`7`** lanterns.
+
+Read the [inventory](https://adventofcode.com/2017/day/1/input). **How many lanterns** are on the busiest shelf?"
+		);
+		assert_eq!(
+			render(articles[1], Style::Markdown),
+			"## --- Part Two ---
+
+Now the Elves want the total number of lanterns.
+
+In the example above, there are **`10`** lanterns in total.
+
+**How many lanterns** are there on all the shelves?"
+		);
+	}
+
+	#[test]
+	fn terminal() {
+		let text = render(articles(PAGE)[0], Style::Terminal);
+		assert!(text.contains("\n    3 4\n    1 2\n\n"));
+		assert!(text.contains("shelves & want"));
+		assert!(!text.contains('<'));
+	}
+
+	#[test]
+	fn examples() {
+		let articles = articles(PAGE);
+		let blocks = code_blocks(articles[0]);
+		assert_eq!(blocks.len(), 1);
+		assert!(blocks[0].0.ends_with("For example:</p>\n"));
+		assert_eq!(blocks[0].1, "3 4\n1 2");
+		assert_eq!(emphasised_code(articles[0]), ["7"]);
+		assert_eq!(emphasised_code(articles[1]), ["10"]);
+		assert!(code_blocks(articles[1]).is_empty());
+	}
+
+	#[test]
+	fn entities() {
+		assert_eq!(decode_entities("&lt;a&gt; &amp;&#39;&#x41;&unknown;"), "<a> &'A&unknown;");
+	}
+
+	#[test]
+	fn text() {
+		assert_eq!(
+			strip_tags(" <p>That's <em>the right</em> answer &amp; more</p>\n"),
+			" That's the right answer & more\n"
+		);
+	}
+}
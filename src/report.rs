@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+use crate::runner::{DayResult, Failure};
+use crate::utils::colored_time;
+
+/// A day taking more time than this is considered slow
+const SLOW: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Default)]
+struct YearReport {
+	ok: usize,
+	invalid: usize,
+	panicked: usize,
+	timed_out: usize,
+	stubbed: usize,
+	slow: usize,
+	total: Duration,
+	/// Time of every part, as (day, part, time)
+	parts: Vec<(u8, u8, Duration)>,
+}
+
+/// Status of the days of every year of a run
+#[derive(Debug, Default)]
+pub struct Report {
+	years: BTreeMap<u16, YearReport>,
+}
+
+impl Report {
+	pub fn add(&mut self, result: &DayResult) {
+		let year = self.years.entry(result.year).or_default();
+
+		if result.has_invalid_input() {
+			year.invalid += 1;
+		} else if result.has_panicked() {
+			year.panicked += 1;
+		} else if result.has_timed_out() {
+			year.timed_out += 1;
+		} else if result.is_stubbed() {
+			year.stubbed += 1;
+		} else if result.total_time() >= SLOW {
+			year.slow += 1;
+		} else {
+			year.ok += 1;
+		}
+
+		year.total += result.total_time();
+		for (part, p) in result.parts().iter().enumerate() {
+			if !matches!(p.answer, Err(Failure::Skipped | Failure::Unselected)) {
+				year.parts.push((result.day, part as u8 + 1, p.time));
+			}
+		}
+	}
+}
+
+impl Display for Report {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "year     ok  invalid  panicked  timed out  stubbed  slow  total")?;
+		for (year, r) in &self.years {
+			writeln!(
+				f,
+				"{:<6}{:>5}{:>9}{:>10}{:>11}{:>9}{:>6}  {}",
+				year,
+				r.ok,
+				r.invalid,
+				r.panicked,
+				r.timed_out,
+				r.stubbed,
+				r.slow,
+				colored_time(r.total)
+			)?;
+		}
+
+		for (year, r) in &self.years {
+			let mut parts = r.parts.clone();
+			parts.sort_by_key(|(_, _, time)| std::cmp::Reverse(*time));
+
+			write!(f, "\n{year} slowest:")?;
+			for (i, (day, part, time)) in parts.iter().take(5).enumerate() {
+				let sep = if i == 0 { "" } else { "," };
+				write!(f, "{sep} d{day:02}p{part} {}", colored_time(*time))?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::runner::solve;
+
+	#[test]
+	fn report() {
+		let mut report = Report::default();
+		report.add(&solve(2024, 1, "3   4\n4   3").unwrap());
+		report.add(&solve(2024, 1, "3   4\n4").unwrap());
+		report.add(&solve(2024, 25, "#####\n.....\n.....\n.....\n.....\n.....\n.....").unwrap());
+
+		let year = &report.years[&2024];
+		assert_eq!((year.ok, year.panicked, year.stubbed, year.slow), (1, 1, 1, 0));
+		assert_eq!(year.parts.len(), 4);
+	}
+}
@@ -0,0 +1,475 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use colored::Colorize;
+
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::utils::colored_time;
+
+/// Reason why a step of a solution has no result
+#[derive(Debug, Clone, PartialEq)]
+pub enum Failure {
+	Panicked(String),
+	TimedOut,
+	/// A previous step failed
+	Skipped,
+	/// Only the other part was selected
+	Unselected,
+	/// The input couldn't be parsed
+	Invalid(ParseError),
+	/// The input couldn't be read or downloaded, with the reason
+	Unavailable(String),
+}
+
+impl Display for Failure {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Panicked(msg) => write!(f, "{}", format!("panicked ({msg})").red()),
+			Self::TimedOut => write!(f, "{}", "timed out".red()),
+			Self::Skipped => write!(f, "{}", "skipped".red()),
+			Self::Unselected => write!(f, "not selected"),
+			Self::Invalid(_) => write!(f, "{}", "invalid input".red()),
+			Self::Unavailable(_) => write!(f, "{}", "unavailable input".red()),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+	pub answer: Result<Answer, Failure>,
+	pub time: Duration,
+}
+
+impl PartResult {
+	fn skipped() -> Self {
+		Self {
+			answer: Err(Failure::Skipped),
+			time: Duration::ZERO,
+		}
+	}
+
+	fn answer(&self) -> &dyn Display {
+		match &self.answer {
+			Ok(answer) => answer,
+			Err(failure) => failure,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayResult {
+	pub year: u16,
+	pub day: u8,
+	pub parse_time: Duration,
+	pub parse_failure: Option<Failure>,
+	pub part1: PartResult,
+	pub part2: PartResult,
+}
+
+impl DayResult {
+	pub fn total_time(&self) -> Duration {
+		self.parse_time + self.part1.time + self.part2.time
+	}
+
+	pub fn parts(&self) -> [&PartResult; 2] {
+		[&self.part1, &self.part2]
+	}
+
+	/// Time of each step, for the steps which gave a result
+	pub fn timings(&self) -> [Option<Duration>; 3] {
+		[
+			self.parse_failure.is_none().then_some(self.parse_time),
+			self.part1.answer.is_ok().then_some(self.part1.time),
+			self.part2.answer.is_ok().then_some(self.part2.time),
+		]
+	}
+
+	pub fn has_panicked(&self) -> bool {
+		matches!(self.parse_failure, Some(Failure::Panicked(_)))
+			|| self.parts().iter().any(|p| matches!(p.answer, Err(Failure::Panicked(_))))
+	}
+
+	pub fn has_timed_out(&self) -> bool {
+		self.parse_failure == Some(Failure::TimedOut) || self.parts().iter().any(|p| p.answer == Err(Failure::TimedOut))
+	}
+
+	pub fn has_invalid_input(&self) -> bool {
+		matches!(self.parse_failure, Some(Failure::Invalid(_) | Failure::Unavailable(_)))
+	}
+
+	/// A part returns a placeholder instead of an answer
+	pub fn is_stubbed(&self) -> bool {
+		self.parts().iter().any(|p| matches!(&p.answer, Ok(answer) if answer.is_none()))
+	}
+}
+
+impl Display for DayResult {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if let Some(failure) = &self.parse_failure {
+			return write!(f, "d{:02}: parse {} ({})", self.day, failure, colored_time(self.parse_time));
+		}
+
+		write!(f, "d{:02}: parse ({})", self.day, colored_time(self.parse_time))?;
+		for (part, p) in self.parts().iter().enumerate() {
+			if p.answer != Err(Failure::Unselected) {
+				write!(f, ", part{} = {} ({})", part + 1, p.answer(), colored_time(p.time))?;
+			}
+		}
+		Ok(())
+	}
+}
+
+thread_local! {
+	/// Location of the last panic of the thread, set by the hook installed with `quiet_panics`
+	static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+	/// The thread is running a step of a solution, whose panic is caught and reported
+	static IN_STEP: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Replace the panic hook, which prints on stderr, by one keeping the location of the panics of the
+/// steps of solutions to report it with their message. Other panics still go to the previous hook.
+pub fn quiet_panics() {
+	let previous = panic::take_hook();
+	panic::set_hook(Box::new(move |info| {
+		if IN_STEP.with(Cell::get) {
+			let location = info.location().map(|l| format!("{}:{}", l.file(), l.line()));
+			LAST_PANIC.with(|last| *last.borrow_mut() = location);
+		} else {
+			previous(info);
+		}
+	}));
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+	let msg = if let Some(s) = payload.downcast_ref::<&str>() {
+		s.to_string()
+	} else if let Some(s) = payload.downcast_ref::<String>() {
+		s.clone()
+	} else {
+		"unknown panic".to_string()
+	};
+
+	match LAST_PANIC.with(|last| last.borrow_mut().take()) {
+		Some(location) => format!("{msg} at {location}"),
+		None => msg,
+	}
+}
+
+/// Run a step of a solution, catching its panic
+fn timed<T>(f: impl FnOnce() -> T) -> (Result<T, Failure>, Duration) {
+	let before = Instant::now();
+	let outer = IN_STEP.with(|step| step.replace(true));
+	let res = panic::catch_unwind(AssertUnwindSafe(f));
+	IN_STEP.with(|step| step.set(outer));
+	let res = res.map_err(|payload| Failure::Panicked(panic_message(payload)));
+	(res, before.elapsed())
+}
+
+/// Outcome of a step of a solution, as soon as it's done
+enum Step {
+	Parse(Option<Failure>, Duration),
+	Part(Result<Answer, Failure>, Duration),
+}
+
+/// Run the steps of a solution one by one, until `send` returns false.
+/// When `part` is given, the other part is reported as unselected without running it.
+fn run_steps(solution: &dyn Solution, input: &str, part: Option<u8>, mut send: impl FnMut(Step) -> bool) {
+	let (parsed, time) = timed(|| solution.parse(input));
+	let parsed = match parsed.and_then(|parsed| parsed.map_err(Failure::Invalid)) {
+		Ok(parsed) => parsed,
+		Err(failure) => {
+			send(Step::Parse(Some(failure), time));
+			return;
+		}
+	};
+
+	if !send(Step::Parse(None, time)) {
+		return;
+	}
+
+	let selected = |p| part.is_none_or(|part| part == p);
+
+	let (answer, time) = if selected(1) {
+		timed(|| parsed.part1())
+	} else {
+		(Err(Failure::Unselected), Duration::ZERO)
+	};
+	if !send(Step::Part(answer, time)) {
+		return;
+	}
+
+	let (answer, time) = if selected(2) {
+		timed(|| parsed.part2())
+	} else {
+		(Err(Failure::Unselected), Duration::ZERO)
+	};
+	send(Step::Part(answer, time));
+}
+
+/// Build the result of a day from its steps, `next` returns `None` if a step took more than `limit`
+fn collect_steps(solution: &dyn Solution, limit: Duration, mut next: impl FnMut() -> Option<Step>) -> DayResult {
+	let mut result = DayResult {
+		year: solution.year(),
+		day: solution.day(),
+		parse_time: Duration::ZERO,
+		parse_failure: None,
+		part1: PartResult::skipped(),
+		part2: PartResult::skipped(),
+	};
+
+	match next() {
+		Some(Step::Parse(None, time)) => result.parse_time = time,
+		Some(Step::Parse(failure, time)) => {
+			result.parse_time = time;
+			result.parse_failure = failure;
+			return result;
+		}
+		_ => {
+			result.parse_time = limit;
+			result.parse_failure = Some(Failure::TimedOut);
+			return result;
+		}
+	}
+
+	for part in [&mut result.part1, &mut result.part2] {
+		match next() {
+			Some(Step::Part(answer, time)) => *part = PartResult { answer, time },
+			_ => {
+				*part = PartResult {
+					answer: Err(Failure::TimedOut),
+					time: limit,
+				};
+				break;
+			}
+		}
+	}
+
+	result
+}
+
+/// Parse the input and run both parts of a solution, or only `part` if it's given.
+/// A panicking step is reported as a failure instead of unwinding.
+pub fn solve_with(solution: &dyn Solution, input: &str, part: Option<u8>) -> DayResult {
+	let mut steps = Vec::new();
+	run_steps(solution, input, part, |step| {
+		steps.push(step);
+		true
+	});
+
+	let mut steps = steps.into_iter();
+	collect_steps(solution, Duration::ZERO, || steps.next())
+}
+
+/// Same as `solve_with`, but a step taking more than `limit` is reported as timed out.
+/// As a thread can't be killed, the step keeps running in the background: it's abandoned, and keeps a CPU
+/// busy until it ends or the program exits.
+pub fn solve_with_limit(solution: &'static dyn Solution, input: &str, part: Option<u8>, limit: Option<Duration>) -> DayResult {
+	let Some(limit) = limit else {
+		return solve_with(solution, input, part);
+	};
+
+	let (sender, receiver) = channel();
+	let input = input.to_string();
+	thread::spawn(move || run_steps(solution, &input, part, |step| sender.send(step).is_ok()));
+
+	collect_steps(solution, limit, || receiver.recv_timeout(limit).ok())
+}
+
+/// Solve a day against the given input, without printing anything.
+pub fn solve(year: u16, day: u8, input: &str) -> Result<DayResult, Error> {
+	Ok(solve_with(crate::find(year, day)?, input, None))
+}
+
+/// A day to solve against an input
+pub struct Task {
+	pub solution: &'static dyn Solution,
+	/// The input, or why it couldn't be read
+	pub input: Result<String, String>,
+	/// Only solve this part, both when missing
+	pub part: Option<u8>,
+}
+
+impl Task {
+	fn solve(&self, limit: Option<Duration>) -> DayResult {
+		let reason = match &self.input {
+			Ok(input) => return solve_with_limit(self.solution, input, self.part, limit),
+			Err(reason) => reason,
+		};
+
+		// The day fails without being solved
+		let part = |part| PartResult {
+			answer: Err(if self.part.is_none_or(|p| p == part) {
+				Failure::Skipped
+			} else {
+				Failure::Unselected
+			}),
+			time: Duration::ZERO,
+		};
+		DayResult {
+			year: self.solution.year(),
+			day: self.solution.day(),
+			parse_time: Duration::ZERO,
+			parse_failure: Some(Failure::Unavailable(reason.clone())),
+			part1: part(1),
+			part2: part(2),
+		}
+	}
+}
+
+/// Solve several tasks on a pool of `jobs` threads, `report` is called with the results in
+/// the order of the given tasks.
+pub fn solve_in_order(tasks: &[Task], jobs: usize, limit: Option<Duration>, mut report: impl FnMut(DayResult)) {
+	if jobs <= 1 {
+		for task in tasks {
+			report(task.solve(limit));
+		}
+		return;
+	}
+
+	let next = AtomicUsize::new(0);
+	let (sender, receiver) = channel();
+
+	thread::scope(|scope| {
+		for _ in 0..jobs.min(tasks.len()) {
+			let (next, sender) = (&next, sender.clone());
+			scope.spawn(move || {
+				loop {
+					let index = next.fetch_add(1, Ordering::Relaxed);
+					let Some(task) = tasks.get(index) else {
+						break;
+					};
+					if sender.send((index, task.solve(limit))).is_err() {
+						break;
+					}
+				}
+			});
+		}
+		drop(sender);
+
+		// Results are buffered until every previous task is reported
+		let mut pending = BTreeMap::new();
+		let mut expected = 0;
+		for (index, result) in receiver {
+			pending.insert(index, result);
+			while let Some(result) = pending.remove(&expected) {
+				report(result);
+				expected += 1;
+			}
+		}
+	});
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use super::*;
+
+	#[test]
+	fn solve_example() {
+		let res = solve(2024, 1, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3").unwrap();
+		assert_eq!((res.year, res.day), (2024, 1));
+		assert_eq!(res.part1.answer, Ok(Answer::Integer(11)));
+		assert_eq!(res.part2.answer, Ok(Answer::Integer(31)));
+	}
+
+	#[test]
+	fn solve_ordered() {
+		let solution = crate::find(2024, 1).unwrap();
+		let tasks: Vec<Task> = (1..=20)
+			.map(|n| Task {
+				solution,
+				input: if n == 5 {
+					Err("missing".to_string())
+				} else {
+					Ok(format!("1   {n}"))
+				},
+				part: None,
+			})
+			.collect();
+
+		let mut answers = Vec::new();
+		solve_in_order(&tasks, 4, None, |result| {
+			assert_eq!(result.has_invalid_input(), answers.len() == 4);
+			answers.push(result.part1.answer);
+		});
+		let expected = (0..20).map(|n| if n == 4 { Err(Failure::Skipped) } else { Ok(Answer::Integer(n)) });
+		assert_eq!(answers, expected.collect::<Vec<_>>());
+	}
+
+	#[test]
+	fn solve_panicking() {
+		let res = solve(2024, 1, "1   2\n3").unwrap();
+		assert!(matches!(res.parse_failure, Some(Failure::Panicked(_))));
+		assert_eq!(res.part1.answer, Err(Failure::Skipped));
+		assert!(res.has_panicked());
+	}
+
+	/// Solution whose second part takes 5 seconds
+	pub(crate) struct Slow;
+
+	impl Solution for Slow {
+		fn year(&self) -> u16 {
+			2015
+		}
+
+		fn day(&self) -> u8 {
+			1
+		}
+
+		fn parse<'a>(&self, _: &'a str) -> Result<Box<dyn crate::solution::Parsed + 'a>, ParseError> {
+			Ok(crate::solution::parsed(
+				(),
+				|_| 1,
+				|_| {
+					thread::sleep(Duration::from_secs(5));
+					2
+				},
+			))
+		}
+	}
+
+	#[test]
+	fn solve_timeout() {
+		let limit = Duration::from_millis(100);
+		let res = solve_with_limit(&Slow, "", None, Some(limit));
+		assert_eq!(res.part1.answer, Ok(Answer::Integer(1)));
+		assert_eq!(res.part2.answer, Err(Failure::TimedOut));
+		assert_eq!(res.part2.time, limit);
+		assert!(res.has_timed_out());
+		assert!(res.timings()[1].is_some() && res.timings()[2].is_none());
+	}
+
+	#[test]
+	fn solve_single_part() {
+		let res = solve_with_limit(&Slow, "", Some(1), None);
+		assert_eq!(res.part1.answer, Ok(Answer::Integer(1)));
+		assert_eq!(res.part2.answer, Err(Failure::Unselected));
+		assert!(!res.to_string().contains("part2"));
+		assert_eq!(res.timings()[2], None);
+	}
+
+	#[test]
+	fn solve_invalid() {
+		let res = solve(2025, 1, "L68\nR3x0").unwrap();
+		let Some(Failure::Invalid(e)) = &res.parse_failure else {
+			panic!("expected an invalid input, got {:?}", res.parse_failure);
+		};
+		assert_eq!((e.year, e.day, e.line, e.column), (2025, 1, 2, 2));
+		assert_eq!(res.part1.answer, Err(Failure::Skipped));
+		assert!(res.has_invalid_input() && !res.has_panicked());
+	}
+
+	#[test]
+	fn solve_missing() {
+		assert_eq!(solve(2025, 25, ""), Err(Error::UnknownDay(2025, 25)));
+	}
+}
@@ -0,0 +1,317 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use regex::{Captures, Regex};
+
+use crate::error::Error;
+use crate::examples::{self, EXAMPLES_DIR, Example};
+use crate::utils::{current_event_year, event_days};
+
+/// Skeleton of a new day, with example tests to fill
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Template {
+	/// A list of lines
+	#[default]
+	Lines,
+	/// A `Grid` of cells parsed from characters
+	Grid,
+	/// An Intcode program
+	Intcode,
+	/// A list of records parsed with a regex
+	RegexRecords,
+	/// The neighbours of each node
+	Graph,
+}
+
+impl Template {
+	fn content(&self) -> &'static str {
+		match self {
+			Self::Lines => include_str!("../templates/lines.rs"),
+			Self::Grid => include_str!("../templates/grid.rs"),
+			Self::Intcode => include_str!("../templates/intcode.rs"),
+			Self::RegexRecords => include_str!("../templates/regex_records.rs"),
+			Self::Graph => include_str!("../templates/graph.rs"),
+		}
+	}
+}
+
+impl FromStr for Template {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"lines" => Ok(Self::Lines),
+			"grid" => Ok(Self::Grid),
+			"intcode" => Ok(Self::Intcode),
+			"regex-records" => Ok(Self::RegexRecords),
+			"graph" => Ok(Self::Graph),
+			_ => Err(format!(
+				"unknown template {s}, expected lines, grid, intcode, regex-records or graph"
+			)),
+		}
+	}
+}
+
+/// Error of a file operation on `path`
+fn io_error(path: &Path) -> impl FnOnce(io::Error) -> Error + '_ {
+	|e| Error::Io(path.display().to_string(), e.to_string())
+}
+
+/// Fill the example tests of a template with an example of the puzzle, read from its file in the examples folder.
+/// Only the answers which are natural numbers replace the placeholder ones, as the template returns numbers.
+fn fill_tests(content: &str, example: &Example, index: u8) -> String {
+	let constant = Regex::new(r#"(?s)const EXAMPLE: &str = ".*?";"#).unwrap();
+	let file = format!(
+		"../../{}/{}/day{:02}-{index}.txt",
+		EXAMPLES_DIR.trim_start_matches("./"),
+		example.year,
+		example.day
+	);
+	let mut content = constant
+		.replace(content, |_: &Captures| {
+			format!("const EXAMPLE: &str = include_str!(\"{file}\").trim_ascii_end();")
+		})
+		.to_string();
+
+	for (part, expected) in example.expected.iter().enumerate() {
+		let Some(expected) = expected.as_ref().filter(|e| e.parse::<u64>().is_ok()) else {
+			continue;
+		};
+		let assertion = Regex::new(&format!(r"(fn example_part{}\(\) \{{\s*assert_eq!\(.*), 0\);", part + 1)).unwrap();
+		content = assertion
+			.replace(&content, |c: &Captures| format!("{}, {expected});", &c[1]))
+			.to_string();
+	}
+
+	content
+}
+
+/// Days having a file in the folder of a year, sorted
+fn existing_days(folder: &Path) -> io::Result<Vec<u8>> {
+	if !folder.is_dir() {
+		return Ok(Vec::new());
+	}
+
+	let file = Regex::new(r"^d(\d{2})\.rs$").unwrap();
+	let mut days = Vec::new();
+	for entry in fs::read_dir(folder)? {
+		let name = entry?.file_name();
+		if let Some(c) = file.captures(&name.to_string_lossy()) {
+			days.push(c[1].parse().unwrap());
+		}
+	}
+	days.sort();
+
+	Ok(days)
+}
+
+/// Content of the module of a year registering `days`.
+/// Other modules and the registrations of days already in `previous` are kept.
+fn year_module(year: u16, days: &[u8], previous: &str) -> String {
+	let day_mod = Regex::new(r"^mod d\d{2};$").unwrap();
+	let other_mod = Regex::new(r"^(pub(\(crate\))? )?mod \w+;$").unwrap();
+	let registration = Regex::new(r"^\s*(solution!\(y\d{4}, d(\d{2}).*)$").unwrap();
+
+	let extra_mods: Vec<&str> = previous.lines().filter(|l| other_mod.is_match(l) && !day_mod.is_match(l)).collect();
+	let registrations: HashMap<u8, &str> = previous
+		.lines()
+		.filter_map(|l| {
+			let c = registration.captures(l)?;
+			Some((c[2].parse().ok()?, c.get(1)?.as_str()))
+		})
+		.collect();
+
+	let mut content = String::from("use crate::solution;\nuse crate::solution::Solution;\n\n");
+	if !extra_mods.is_empty() {
+		content += &extra_mods.join("\n");
+		content += "\n\n";
+	}
+	for day in days {
+		writeln!(content, "mod d{day:02};").unwrap();
+	}
+
+	content += "\npub const SOLUTIONS: &[&dyn Solution] = &[\n";
+	for day in days {
+		match registrations.get(day) {
+			Some(registration) => writeln!(content, "\t{registration}").unwrap(),
+			None => writeln!(content, "\tsolution!(y{year}, d{day:02}),").unwrap(),
+		}
+	}
+	content += "];\n";
+
+	content
+}
+
+/// Insert `line` in the sorted block of lines matching `pattern`, `None` if there's no such block
+fn insert_sorted(content: &str, pattern: &Regex, line: &str) -> Option<String> {
+	let mut lines: Vec<&str> = content.lines().collect();
+	let block: Vec<usize> = (0..lines.len()).filter(|i| pattern.is_match(lines[*i])).collect();
+	let last = *block.last()?;
+
+	if !lines.contains(&line) {
+		let index = block.into_iter().find(|i| lines[*i] > line).unwrap_or(last + 1);
+		lines.insert(index, line);
+	}
+
+	Some(lines.join("\n") + "\n")
+}
+
+/// Add a year to the modules and the registry of the library
+fn register_year(lib: &str, year: u16) -> Option<String> {
+	let lib = insert_sorted(lib, &Regex::new(r"^mod y\d{4};$").unwrap(), &format!("mod y{year};"))?;
+	insert_sorted(
+		&lib,
+		&Regex::new(r"^\ty\d{4}::SOLUTIONS,$").unwrap(),
+		&format!("\ty{year}::SOLUTIONS,"),
+	)
+}
+
+/// Create the file of a day from `template` in the sources under `root`, and register it in the module of its year.
+/// The year is registered in the library when it's a new one.
+/// Without a `day`, the one after the last day of the year is created.
+/// The tests of the template use the first of the `examples` of the day when there's one, which is saved in the examples folder.
+/// Returns the written files.
+pub fn new_day(
+	root: &Path,
+	year: u16,
+	day: Option<u8>,
+	template: Template,
+	examples: impl Fn(u8) -> Vec<Example>,
+) -> Result<Vec<PathBuf>, Error> {
+	if !(2015..=current_event_year()).contains(&year) {
+		return Err(Error::InvalidYear(year));
+	}
+
+	let folder = root.join(format!("src/y{year}"));
+	let mut days = existing_days(&folder).map_err(io_error(&folder))?;
+	let day = match day {
+		Some(day) if (1..=event_days(year)).contains(&day) => day,
+		Some(day) => return Err(Error::InvalidDay(year, day)),
+		None => match days.last() {
+			Some(last) if *last >= event_days(year) => return Err(Error::YearDone(year)),
+			Some(last) => last + 1,
+			None => 1,
+		},
+	};
+
+	let day_file = folder.join(format!("d{day:02}.rs"));
+	if day_file.exists() {
+		return Err(Error::Exists(day_file.display().to_string()));
+	}
+
+	let lib_file = root.join("src/lib.rs");
+	let lib = fs::read_to_string(&lib_file).map_err(io_error(&lib_file))?;
+	let registered = register_year(&lib, year).ok_or_else(|| Error::Unregistered(lib_file.display().to_string()))?;
+
+	let mod_file = folder.join("mod.rs");
+	let previous = fs::read_to_string(&mod_file).unwrap_or_default();
+	days.push(day);
+	days.sort();
+
+	fs::create_dir_all(&folder).map_err(io_error(&folder))?;
+	let (content, example_files) = match examples(day).first() {
+		Some(example) => {
+			let dir = root.join(EXAMPLES_DIR);
+			let (index, files) = examples::save(&dir, example).map_err(io_error(&dir))?;
+			(fill_tests(template.content(), example, index), files)
+		}
+		None => (template.content().to_string(), Vec::new()),
+	};
+	fs::write(&day_file, content).map_err(io_error(&day_file))?;
+	fs::write(&mod_file, year_module(year, &days, &previous)).map_err(io_error(&mod_file))?;
+	let mut written = vec![day_file, mod_file];
+	written.extend(example_files);
+	if registered != lib {
+		fs::write(&lib_file, registered).map_err(io_error(&lib_file))?;
+		written.push(lib_file);
+	}
+
+	Ok(written)
+}
+
+// Templates are compiled and their example tests are run, to make sure new days build
+#[cfg(test)]
+#[path = "../templates/graph.rs"]
+mod graph_template;
+#[cfg(test)]
+#[path = "../templates/grid.rs"]
+mod grid_template;
+#[cfg(test)]
+#[path = "../templates/intcode.rs"]
+mod intcode_template;
+#[cfg(test)]
+#[path = "../templates/lines.rs"]
+mod lines_template;
+#[cfg(test)]
+#[path = "../templates/regex_records.rs"]
+mod regex_records_template;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::tests::TempDir;
+
+	#[test]
+	fn keep_year_modules() {
+		let modules = [
+			(2019, include_str!("y2019/mod.rs")),
+			(2024, include_str!("y2024/mod.rs")),
+			(2025, include_str!("y2025/mod.rs")),
+		];
+		for (year, module) in modules {
+			let days: Vec<u8> = (1..=event_days(year))
+				.filter(|d| module.contains(&format!("mod d{d:02};")))
+				.collect();
+			assert_eq!(year_module(year, &days, module), module);
+		}
+	}
+
+	#[test]
+	fn scaffold_days() {
+		let root = TempDir::new("scaffold");
+		fs::create_dir_all(root.join("src")).unwrap();
+		let lib = "mod y2015;\nmod y2017;\n\nconst YEARS: &[&[&dyn Solution]] = &[\n\ty2015::SOLUTIONS,\n\ty2017::SOLUTIONS,\n];\n";
+		fs::write(root.join("src/lib.rs"), lib).unwrap();
+		let none = |_| Vec::new();
+
+		assert_eq!(new_day(&root, 2016, None, Template::Lines, none).unwrap().len(), 3);
+		assert_eq!(new_day(&root, 2016, Some(3), Template::Grid, none).unwrap().len(), 2);
+		assert_eq!(new_day(&root, 2016, None, Template::Graph, none).unwrap().len(), 2);
+		assert!(matches!(
+			new_day(&root, 2016, Some(3), Template::Lines, none),
+			Err(Error::Exists(_))
+		));
+		assert!(matches!(
+			new_day(&root, 2016, Some(26), Template::Lines, none),
+			Err(Error::InvalidDay(2016, 26))
+		));
+		assert!(matches!(
+			new_day(&root, 2014, None, Template::Lines, none),
+			Err(Error::InvalidYear(2014))
+		));
+
+		let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+		assert!(lib.contains("mod y2015;\nmod y2016;\nmod y2017;\n"));
+		assert!(lib.contains("\ty2015::SOLUTIONS,\n\ty2016::SOLUTIONS,\n\ty2017::SOLUTIONS,\n"));
+
+		let module = fs::read_to_string(root.join("src/y2016/mod.rs")).unwrap();
+		assert!(module.contains("mod d01;\nmod d03;\nmod d04;\n"));
+		assert!(module.contains("\tsolution!(y2016, d03),\n\tsolution!(y2016, d04),\n];"));
+
+		let page = include_str!("../tests/fixtures/puzzle.html");
+		let examples = |day| crate::examples::extract(2016, day, page);
+		assert_eq!(new_day(&root, 2016, Some(5), Template::Lines, examples).unwrap().len(), 4);
+		let day = fs::read_to_string(root.join("src/y2016/d05.rs")).unwrap();
+		assert!(day.contains("const EXAMPLE: &str = include_str!(\"../../data/examples/2016/day05-1.txt\").trim_ascii_end();"));
+		assert_eq!(
+			fs::read_to_string(root.join("data/examples/2016/day05-1.txt")).unwrap(),
+			"3 4\n1 2\n"
+		);
+		assert!(day.contains("assert_eq!(part1(&parse_input(EXAMPLE)), 7);"));
+		assert!(day.contains("assert_eq!(part2(&parse_input(EXAMPLE)), 10);"));
+	}
+}
@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde_json::Value;
+
+/// Environment variable taking precedence over the stored sessions
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable selecting the profile, when `--profile` is missing
+pub const PROFILE_VAR: &str = "AOC_PROFILE";
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Session file of older versions, still read for the default profile
+const LEGACY_FILE: &str = "./SESSION";
+
+static PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Use the session of `profile` for the rest of the program
+pub fn select_profile(profile: &str) {
+	let _ = PROFILE.set(profile.to_string());
+}
+
+/// Profile selected with `select_profile`, `AOC_PROFILE` or the default one
+pub fn profile() -> String {
+	PROFILE
+		.get()
+		.cloned()
+		.or_else(|| std::env::var(PROFILE_VAR).ok().filter(|p| !p.is_empty()))
+		.unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+}
+
+/// Folder of the configuration of the user
+fn config_dir() -> Option<PathBuf> {
+	let var = |name: &str| std::env::var_os(name).filter(|v| !v.is_empty()).map(PathBuf::from);
+
+	if cfg!(windows) {
+		var("APPDATA")
+	} else if cfg!(target_os = "macos") {
+		var("HOME").map(|home| home.join("Library/Application Support"))
+	} else {
+		var("XDG_CONFIG_HOME").or_else(|| var("HOME").map(|home| home.join(".config")))
+	}
+}
+
+/// File storing the session of each profile: `{"default": "...", "alice": "..."}`
+pub fn config_file() -> Option<PathBuf> {
+	config_dir().map(|dir| dir.join("advent-of-code/sessions.json"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+	Env,
+	Config(PathBuf),
+	Legacy,
+	Prompt,
+}
+
+impl Display for Source {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Env => write!(f, "the {SESSION_VAR} environment variable"),
+			Self::Config(path) => write!(f, "{}", path.display()),
+			Self::Legacy => write!(f, "{LEGACY_FILE}"),
+			Self::Prompt => write!(f, "the prompt"),
+		}
+	}
+}
+
+/// Session token of Advent of Code, with where it was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+	pub token: String,
+	pub source: Source,
+}
+
+/// Sessions of the profiles stored in `path`, empty if it doesn't exist
+pub fn read_sessions(path: &Path) -> io::Result<BTreeMap<String, String>> {
+	let content = match fs::read_to_string(path) {
+		Ok(content) => content,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+		Err(e) => return Err(e),
+	};
+	let json: Value = serde_json::from_str(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+	Ok(json
+		.as_object()
+		.into_iter()
+		.flatten()
+		.filter_map(|(profile, token)| Some((profile.clone(), token.as_str()?.to_string())))
+		.collect())
+}
+
+fn empty_token() -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidInput, "the session is empty")
+}
+
+/// Store the session of `profile` in `path`, keeping the other profiles.
+/// The file is only readable by its owner: it's written to a new file created with these permissions, which replaces it.
+pub fn store_session(path: &Path, profile: &str, token: &str) -> io::Result<()> {
+	if token.trim().is_empty() {
+		return Err(empty_token());
+	}
+
+	let mut sessions = read_sessions(path)?;
+	sessions.insert(profile.to_string(), token.to_string());
+
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent)?;
+	}
+	let new = path.with_extension("json.new");
+	let _ = fs::remove_file(&new);
+	let mut options = fs::OpenOptions::new();
+	options.write(true).create_new(true);
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::OpenOptionsExt;
+		options.mode(0o600);
+	}
+	let mut file = options.open(&new)?;
+	file.write_all((serde_json::to_string_pretty(&sessions)? + "\n").as_bytes())?;
+	drop(file);
+
+	fs::rename(&new, path)
+}
+
+/// Session of `profile`, from the environment variable `env`, then `config` and the legacy file for the default profile
+pub fn find_session(env: Option<String>, config: Option<&Path>, profile: &str) -> io::Result<Option<Session>> {
+	if let Some(token) = env.map(|t| t.trim().to_string()).filter(|t| !t.is_empty()) {
+		return Ok(Some(Session {
+			token,
+			source: Source::Env,
+		}));
+	}
+
+	if let Some(config) = config
+		&& let Some(token) = read_sessions(config)?.remove(profile).filter(|t| !t.trim().is_empty())
+	{
+		return Ok(Some(Session {
+			token,
+			source: Source::Config(config.to_path_buf()),
+		}));
+	}
+
+	let legacy = Path::new(LEGACY_FILE);
+	if profile == DEFAULT_PROFILE && legacy.is_file() {
+		let token = fs::read_to_string(legacy)?.trim().to_string();
+		if !token.is_empty() {
+			return Ok(Some(Session {
+				token,
+				source: Source::Legacy,
+			}));
+		}
+	}
+
+	Ok(None)
+}
+
+/// Session of `profile` read from a line of the standard input, with a prompt when it's a terminal
+pub fn read_token(profile: &str) -> io::Result<String> {
+	if io::stdin().is_terminal() {
+		print!("session of profile {profile}: ");
+		io::stdout().flush()?;
+	}
+	let mut token = String::new();
+	io::stdin().read_line(&mut token)?;
+	let token = token.trim().to_string();
+	if token.is_empty() {
+		return Err(empty_token());
+	}
+
+	Ok(token)
+}
+
+/// Session of the selected profile, asking it to the user and storing it when it's unknown
+pub fn session() -> io::Result<Session> {
+	let profile = profile();
+	let config = config_file();
+	if let Some(session) = find_session(std::env::var(SESSION_VAR).ok(), config.as_deref(), &profile)? {
+		return Ok(session);
+	}
+
+	let token = read_token(&profile)?;
+	match &config {
+		Some(config) => store_session(config, &profile, &token)?,
+		None => eprintln!("no configuration folder, the session won't be stored"),
+	}
+
+	Ok(Session {
+		token,
+		source: Source::Prompt,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::tests::TempDir;
+
+	#[test]
+	fn profiles() {
+		let dir = TempDir::new("session");
+		let config = dir.join("advent-of-code/sessions.json");
+		assert!(read_sessions(&config).unwrap().is_empty());
+
+		store_session(&config, "default", "abc").unwrap();
+		store_session(&config, "alice", "def").unwrap();
+		store_session(&config, "default", "ghi").unwrap();
+		assert_eq!(
+			store_session(&config, "bob", " \n").unwrap_err().kind(),
+			io::ErrorKind::InvalidInput
+		);
+		let sessions = read_sessions(&config).unwrap();
+		assert_eq!(sessions.len(), 2);
+		assert_eq!(sessions["default"], "ghi");
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			assert_eq!(fs::metadata(&config).unwrap().permissions().mode() & 0o777, 0o600);
+		}
+
+		let session = find_session(None, Some(&config), "alice").unwrap().unwrap();
+		assert_eq!(session.token, "def");
+		assert_eq!(session.source, Source::Config(config.clone()));
+
+		let session = find_session(Some("env\n".to_string()), Some(&config), "alice").unwrap().unwrap();
+		assert_eq!(session.token, "env");
+		assert_eq!(session.source, Source::Env);
+
+		assert_eq!(find_session(Some(String::new()), Some(&config), "bob").unwrap(), None);
+	}
+}
@@ -0,0 +1,88 @@
+use crate::answer::Answer;
+use crate::parse::ParseError;
+
+/// A puzzle solution registered for a given year and day.
+pub trait Solution: Sync {
+	fn year(&self) -> u16;
+	fn day(&self) -> u8;
+	fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError>;
+
+	/// Part returning a placeholder instead of an answer, like the second part of the last day
+	fn placeholder(&self) -> Option<u8> {
+		None
+	}
+}
+
+/// Parsed input of a day, ready to be solved.
+pub trait Parsed {
+	fn part1(&self) -> Answer;
+	fn part2(&self) -> Answer;
+}
+
+struct Day<T, A, B> {
+	input: T,
+	part1: fn(&T) -> A,
+	part2: fn(&T) -> B,
+}
+
+impl<T, A: Into<Answer>, B: Into<Answer>> Parsed for Day<T, A, B> {
+	fn part1(&self) -> Answer {
+		(self.part1)(&self.input).into()
+	}
+
+	fn part2(&self) -> Answer {
+		(self.part2)(&self.input).into()
+	}
+}
+
+pub fn parsed<'a, T: 'a, A: Into<Answer> + 'a, B: Into<Answer> + 'a>(
+	input: T,
+	part1: fn(&T) -> A,
+	part2: fn(&T) -> B,
+) -> Box<dyn Parsed + 'a> {
+	Box::new(Day { input, part1, part2 })
+}
+
+/// Register the solution of a day, whose module has `parse_input`, `part1` and `part2` functions.
+/// `parse_input` returns a `Result<_, ParseError>` when the solution is marked as `fallible`.
+#[macro_export]
+macro_rules! solution {
+	($year:path, $day:path) => {
+		$crate::solution!(@impl $year, $day, Ok, None)
+	};
+	($year:path, $day:path, placeholder = $part:literal) => {
+		$crate::solution!(@impl $year, $day, Ok, Some($part))
+	};
+	($year:path, $day:path, fallible) => {
+		$crate::solution!(@impl $year, $day, std::convert::identity, None)
+	};
+	($year:path, $day:path, fallible, placeholder = $part:literal) => {
+		$crate::solution!(@impl $year, $day, std::convert::identity, Some($part))
+	};
+	(@impl $year:path, $day:path, $result:expr, $placeholder:expr) => {{
+		struct Day;
+
+		impl $crate::solution::Solution for Day {
+			fn year(&self) -> u16 {
+				$crate::utils::extract_integer(stringify!($year)).unwrap()
+			}
+
+			fn day(&self) -> u8 {
+				$crate::utils::extract_integer(stringify!($day)).unwrap()
+			}
+
+			fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn $crate::solution::Parsed + 'a>, $crate::parse::ParseError> {
+				use $day::{parse_input, part1, part2};
+
+				let input = ($result)(parse_input(input)).map_err(|e: $crate::parse::ParseError| e.of(self.year(), self.day()))?;
+				Ok($crate::solution::parsed(input, |input| part1(input), |input| part2(input)))
+			}
+
+			fn placeholder(&self) -> Option<u8> {
+				$placeholder
+			}
+		}
+
+		&Day
+	}};
+}
@@ -0,0 +1,319 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use colored::Colorize;
+use regex::Regex;
+use serde_json::{Map, Value, json};
+
+use crate::answer::Answer;
+use crate::client::AocClient;
+use crate::error::Error;
+use crate::puzzle;
+use crate::verify::AnswerStore;
+
+/// Folder of the submitted answers, stored as one JSON file per year:
+/// `{"01": {"part1": [{"answer": "...", "outcome": "too high"}]}}`
+pub const GUESSES_DIR: &str = "./answers/guesses";
+
+/// Response of the website to an answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+	Correct,
+	TooHigh,
+	TooLow,
+	Wrong,
+	/// An answer was sent too recently, with the time left to wait
+	RateLimited(Option<Duration>),
+	/// The part is already solved or not unlocked
+	WrongLevel,
+	/// The response couldn't be understood, with its text
+	Unknown(String),
+}
+
+impl Outcome {
+	/// Read the outcome from the page of the response
+	pub fn parse(page: &str) -> Self {
+		// The message is in the article of the page
+		let text = puzzle::strip_tags(puzzle::articles(page).first().unwrap_or(&page))
+			.trim()
+			.to_string();
+
+		if text.contains("That's the right answer") {
+			Self::Correct
+		} else if text.contains("your answer is too high") {
+			Self::TooHigh
+		} else if text.contains("your answer is too low") {
+			Self::TooLow
+		} else if text.contains("That's not the right answer") {
+			Self::Wrong
+		} else if text.contains("You gave an answer too recently") {
+			let wait = Regex::new(r"You have (?:(\d+)m ?)?(\d+)s left to wait").unwrap();
+			Self::RateLimited(wait.captures(&text).map(|c| {
+				let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+				Duration::from_secs(minutes * 60 + c[2].parse::<u64>().unwrap())
+			}))
+		} else if text.contains("You don't seem to be solving the right level") {
+			Self::WrongLevel
+		} else {
+			Self::Unknown(text)
+		}
+	}
+
+	/// Name of the outcome in the guesses file, if the answer was judged
+	fn key(&self) -> Option<&'static str> {
+		match self {
+			Self::Correct => Some("correct"),
+			Self::TooHigh => Some("too high"),
+			Self::TooLow => Some("too low"),
+			Self::Wrong => Some("wrong"),
+			_ => None,
+		}
+	}
+
+	fn from_key(key: &str) -> Option<Self> {
+		match key {
+			"correct" => Some(Self::Correct),
+			"too high" => Some(Self::TooHigh),
+			"too low" => Some(Self::TooLow),
+			"wrong" => Some(Self::Wrong),
+			_ => None,
+		}
+	}
+}
+
+impl Display for Outcome {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Correct => write!(f, "{}", "correct".green()),
+			Self::TooHigh => write!(f, "{}", "wrong, too high".red()),
+			Self::TooLow => write!(f, "{}", "wrong, too low".red()),
+			Self::Wrong => write!(f, "{}", "wrong".red()),
+			Self::RateLimited(Some(wait)) => write!(f, "{}", format!("answered too recently, wait {wait:?}").yellow()),
+			Self::RateLimited(None) => write!(f, "{}", "answered too recently".yellow()),
+			Self::WrongLevel => write!(f, "{}", "this part is already solved or not unlocked".yellow()),
+			Self::Unknown(text) => write!(f, "unknown response: {text}"),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+	pub answer: String,
+	pub outcome: Outcome,
+}
+
+/// Answers sent for the days of a year, with their outcome
+pub struct Guesses {
+	path: PathBuf,
+	days: BTreeMap<u8, [Vec<Guess>; 2]>,
+}
+
+impl Guesses {
+	/// File of the guesses of a year in `dir`
+	pub fn file(dir: &Path, year: u16) -> PathBuf {
+		dir.join(format!("{year}.json"))
+	}
+
+	pub fn load(dir: &Path, year: u16) -> io::Result<Self> {
+		let path = Self::file(dir, year);
+		let mut days = BTreeMap::new();
+
+		match fs::read_to_string(&path) {
+			Ok(content) => {
+				let json: Value = serde_json::from_str(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+				for (day, parts) in json.as_object().into_iter().flatten() {
+					let Ok(day) = day.parse() else { continue };
+					let part = |key: &str| -> Vec<Guess> {
+						let guesses = parts.get(key).and_then(Value::as_array).into_iter().flatten();
+						guesses
+							.filter_map(|g| {
+								Some(Guess {
+									answer: g.get("answer")?.as_str()?.to_string(),
+									outcome: Outcome::from_key(g.get("outcome")?.as_str()?)?,
+								})
+							})
+							.collect()
+					};
+					days.insert(day, [part("part1"), part("part2")]);
+				}
+			}
+			Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+			Err(e) => return Err(e),
+		}
+
+		Ok(Self { path, days })
+	}
+
+	pub fn save(&self) -> io::Result<()> {
+		let mut json = Map::new();
+		for (day, parts) in &self.days {
+			let mut obj = Map::new();
+			for (key, guesses) in ["part1", "part2"].iter().zip(parts) {
+				if !guesses.is_empty() {
+					let guesses = guesses.iter().map(|g| json!({"answer": g.answer, "outcome": g.outcome.key()}));
+					obj.insert(key.to_string(), Value::Array(guesses.collect()));
+				}
+			}
+			json.insert(format!("{day:02}"), Value::Object(obj));
+		}
+
+		if let Some(parent) = self.path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		fs::write(&self.path, serde_json::to_string_pretty(&Value::Object(json))? + "\n")
+	}
+
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	pub fn get(&self, day: u8, part: u8) -> &[Guess] {
+		self.days.get(&day).map_or(&[], |parts| &parts[part as usize - 1])
+	}
+
+	/// Record the answer if the website judged it
+	pub fn add(&mut self, day: u8, part: u8, answer: &str, outcome: &Outcome) {
+		if outcome.key().is_some() {
+			self.days.entry(day).or_default()[part as usize - 1].push(Guess {
+				answer: answer.to_string(),
+				outcome: outcome.clone(),
+			});
+		}
+	}
+
+	/// Why `answer` is known to be wrong from the previous guesses
+	pub fn ruled_out(&self, day: u8, part: u8, answer: &str) -> Option<String> {
+		let guesses = self.get(day, part);
+		if let Some(guess) = guesses.iter().find(|g| g.answer == answer) {
+			return Some(format!("{answer} was already sent, it is {}", guess.outcome.key().unwrap()));
+		}
+
+		let value: i128 = answer.parse().ok()?;
+		let bound = |outcome: Outcome| {
+			guesses
+				.iter()
+				.filter(move |g| g.outcome == outcome)
+				.filter_map(|g| g.answer.parse::<i128>().ok())
+		};
+		if let Some(high) = bound(Outcome::TooHigh).min().filter(|high| value >= *high) {
+			return Some(format!("{answer} is not lower than {high}, which is too high"));
+		}
+		if let Some(low) = bound(Outcome::TooLow).max().filter(|low| value <= *low) {
+			return Some(format!("{answer} is not higher than {low}, which is too low"));
+		}
+
+		None
+	}
+}
+
+/// Send the answer of a part unless it's ruled out by the known answer or the previous guesses, or `force` is set.
+/// The guess is recorded, and the answer too when it's correct.
+pub fn send(
+	client: &AocClient,
+	answers: &mut AnswerStore,
+	guesses: &mut Guesses,
+	(year, day, part): (u16, u8, u8),
+	answer: &str,
+	force: bool,
+) -> Result<Outcome, Error> {
+	if !force {
+		match answers.get(year, day, part) {
+			Some(known) if known == answer => return Err(Error::Refused(format!("{answer} is already the answer of this part"))),
+			Some(known) => return Err(Error::Refused(format!("the answer of this part is already known: {known}"))),
+			None => (),
+		}
+		if let Some(reason) = guesses.ruled_out(day, part, answer) {
+			return Err(Error::Refused(reason));
+		}
+	}
+
+	let outcome = Outcome::parse(&client.answer(year, day, part, answer)?);
+	guesses.add(day, part, answer, &outcome);
+	if outcome == Outcome::Correct {
+		answers.record(year, day, part, &Answer::from(answer));
+	}
+
+	Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::client::tests::serve;
+	use crate::utils::tests::TempDir;
+
+	fn page(message: &str) -> String {
+		format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+	}
+
+	#[test]
+	fn outcomes() {
+		assert_eq!(
+			Outcome::parse(&page("That's the right answer! You are one gold star closer.")),
+			Outcome::Correct
+		);
+		assert_eq!(
+			Outcome::parse(&page("That's not the right answer; your answer is too high.")),
+			Outcome::TooHigh
+		);
+		assert_eq!(
+			Outcome::parse(&page("That's not the right answer; your answer is too low.")),
+			Outcome::TooLow
+		);
+		assert_eq!(
+			Outcome::parse(&page("That's not the right answer.  If you're stuck...")),
+			Outcome::Wrong
+		);
+		assert_eq!(
+			Outcome::parse(&page("You gave an answer too recently. You have 1m 5s left to wait.")),
+			Outcome::RateLimited(Some(Duration::from_secs(65)))
+		);
+		assert_eq!(
+			Outcome::parse(&page("You gave an answer too recently. You have 34s left to wait.")),
+			Outcome::RateLimited(Some(Duration::from_secs(34)))
+		);
+		assert_eq!(
+			Outcome::parse(&page("You don't seem to be solving the right level.  Did you already complete it?")),
+			Outcome::WrongLevel
+		);
+	}
+
+	#[test]
+	fn submit_flow() {
+		let dir = TempDir::new("submit");
+		let mut answers = AnswerStore::new(&dir);
+		answers.load(2017).unwrap();
+		let mut guesses = Guesses::load(&dir.join("guesses"), 2017).unwrap();
+
+		let (url, server) = serve(vec![
+			(200, page("That's not the right answer; your answer is too high.")),
+			(200, page("That's not the right answer; your answer is too low.")),
+			(200, page("You gave an answer too recently. You have 30s left to wait.")),
+			(200, page("That's the right answer!")),
+		]);
+		let client = AocClient::new("abc").unwrap().with_base_url(&url);
+		let mut send = |answer: &str| send(&client, &mut answers, &mut guesses, (2017, 1, 1), answer, false);
+
+		assert_eq!(send("100").unwrap(), Outcome::TooHigh);
+		assert!(matches!(send("100"), Err(Error::Refused(_))));
+		assert!(matches!(send("150"), Err(Error::Refused(_))));
+		assert_eq!(send("20").unwrap(), Outcome::TooLow);
+		assert!(matches!(send("10"), Err(Error::Refused(_))));
+		assert!(matches!(send("50").unwrap(), Outcome::RateLimited(_)));
+		assert_eq!(send("50").unwrap(), Outcome::Correct);
+		assert!(matches!(send("60"), Err(Error::Refused(_))));
+
+		assert_eq!(server.join().unwrap().len(), 4);
+		assert_eq!(answers.get(2017, 1, 1), Some("50"));
+
+		guesses.save().unwrap();
+		let guesses = Guesses::load(&dir.join("guesses"), 2017).unwrap();
+		let sent: Vec<&str> = guesses.get(1, 1).iter().map(|g| g.answer.as_str()).collect();
+		assert_eq!(sent, ["100", "20", "50"]);
+		assert!(guesses.get(1, 2).is_empty());
+	}
+}
@@ -1,34 +1,14 @@
-use std::io::Write;
+use std::io::Read;
 use std::path::Path;
-use std::time::Duration;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use std::{fs, io};
 
 use colored::{ColoredString, Colorize};
-use reqwest::blocking::Client;
-use reqwest::header::{HeaderMap, COOKIE};
 
-fn read_input(msg: &str) -> std::io::Result<String> {
-	let mut buffer = String::new();
-	print!("{}", msg);
-	io::stdout().flush()?;
-	io::stdin().read_line(&mut buffer)?;
-	Ok(buffer)
-}
-
-fn send_get(session: &str, url: &str) -> reqwest::Result<String> {
-	// Building HTTP Header
-	let mut headers = HeaderMap::new();
-	headers.append(COOKIE, session.parse().expect("Couldn't parse session"));
-	let client = Client::builder()
-		.user_agent("github.com/seysn/advent-of-code-rust by seys.nicolas@gmail.com")
-		.default_headers(headers)
-		.build()?;
-
-	// Fetch response
-	let res = client.execute(client.get(url).build()?)?;
-
-	Ok(res.text()?.trim_end().to_string())
-}
+use crate::client::{AocClient, BASE_URL_VAR};
+use crate::error::Error;
+use crate::{puzzle, session};
 
 pub fn extract_integer<T: std::str::FromStr>(s: &str) -> Result<T, &str> {
 	let res = s.chars().filter(|c| c.is_numeric()).collect::<String>().parse::<T>();
@@ -39,53 +19,222 @@ pub fn extract_integer<T: std::str::FromStr>(s: &str) -> Result<T, &str> {
 	}
 }
 
-pub fn get_session() -> String {
-	let session_file = Path::new("SESSION");
+static CLIENT: OnceLock<AocClient> = OnceLock::new();
 
-	if session_file.is_file() {
-		// Session file exists, read session key in it
-		fs::read_to_string(session_file)
-			.expect("Unable to read session file")
-			.trim()
-			.to_string()
-	} else {
-		// Session file doesn't exist, ask to user
-		let session = read_input("session: ").expect("Unable to read input");
+/// Client authenticated with `token`, using the server of `AOC_BASE_URL` when it's set
+pub fn aoc_client(token: &str) -> Result<AocClient, Error> {
+	let client = AocClient::new(token)?;
+	Ok(match std::env::var(BASE_URL_VAR) {
+		Ok(url) if !url.is_empty() => client.with_base_url(&url),
+		_ => client,
+	})
+}
+
+/// Client authenticated with the session of the selected profile, which is asked once
+pub fn client() -> Result<&'static AocClient, Error> {
+	if let Some(client) = CLIENT.get() {
+		return Ok(client);
+	}
+
+	let session = session::session().map_err(|e| Error::Session(e.to_string()))?;
+	let client = aoc_client(&session.token)?;
+	Ok(CLIENT.get_or_init(|| client))
+}
+
+/// Name of the user logged in with `token`, `None` if the session is invalid or has expired
+pub fn logged_user(token: &str) -> Result<Option<String>, Error> {
+	let page = aoc_client(token)?.get(&format!("/{}", current_event_year()))?;
+
+	Ok(user_name(&String::from_utf8_lossy(&page)))
+}
+
+fn user_name(page: &str) -> Option<String> {
+	let user = regex::Regex::new(r#"<div class="user">([^<]*)"#).unwrap();
+	user.captures(page).map(|c| c[1].trim().to_string())
+}
+
+pub fn input_path(year: u16, day: u8) -> String {
+	format!("./input/{}/day{:02}.txt", year, day)
+}
+
+/// Page of the puzzle description, cached next to the input
+pub fn puzzle_path(year: u16, day: u8) -> String {
+	format!("./input/{}/day{:02}.html", year, day)
+}
+
+/// Download the page of a puzzle and cache it, when it has a description
+pub fn fetch_puzzle(year: u16, day: u8) -> Result<String, Error> {
+	let page = client()?
+		.get(&format!("/{year}/day/{day}"))
+		.map_err(|e| Error::Download(year, day, e.to_string()))?;
+	let page = String::from_utf8_lossy(&page).into_owned();
+	if puzzle::articles(&page).is_empty() {
+		return Err(Error::Download(year, day, "the page has no puzzle description".to_string()));
+	}
+
+	let filename = puzzle_path(year, day);
+	fs::create_dir_all(Path::new(&filename).parent().unwrap())
+		.and_then(|_| fs::write(&filename, &page))
+		.map_err(|e| Error::Io(filename.clone(), e.to_string()))?;
+
+	Ok(page)
+}
+
+/// Private leaderboard, cached next to the inputs of its year
+pub fn leaderboard_path(year: u16, id: u64) -> String {
+	format!("./input/{}/leaderboard-{}.json", year, id)
+}
+
+pub fn cached_puzzle(year: u16, day: u8) -> Option<String> {
+	fs::read_to_string(puzzle_path(year, day)).ok()
+}
+
+/// Decode an input, removing its byte order mark, and use `\n` line endings.
+/// Whitespace is trimmed at the end of the input only, as some puzzles align columns with trailing spaces.
+pub fn normalize_input(bytes: &[u8]) -> String {
+	let utf16 = |rest: &[u8], from: fn([u8; 2]) -> u16| {
+		let units: Vec<u16> = rest.chunks_exact(2).map(|c| from([c[0], c[1]])).collect();
+		String::from_utf16_lossy(&units)
+	};
+	let text = match bytes {
+		[0xef, 0xbb, 0xbf, rest @ ..] => String::from_utf8_lossy(rest).into_owned(),
+		[0xff, 0xfe, rest @ ..] => utf16(rest, u16::from_le_bytes),
+		[0xfe, 0xff, rest @ ..] => utf16(rest, u16::from_be_bytes),
+		_ => String::from_utf8_lossy(bytes).into_owned(),
+	};
+
+	text.replace("\r\n", "\n").replace('\r', "\n").trim_end().to_string()
+}
 
-		// Write session in file to avoid asking user everytime
-		fs::write(session_file, session.trim()).unwrap();
+/// Why a downloaded text is not a puzzle input, like an error page saved instead of it
+pub fn invalid_input(input: &str) -> Option<&'static str> {
+	let first_line = input.lines().next().unwrap_or_default();
+	let start = first_line.trim_start().to_ascii_lowercase();
 
-		session.trim().to_string()
+	if input.is_empty() {
+		Some("it is empty")
+	} else if start.starts_with("<!doctype html") || start.starts_with("<html") {
+		Some("it is an HTML page, not an input")
+	} else if first_line.contains("Please log in") {
+		Some("the session is missing or has expired")
+	} else if first_line.contains("before it unlocks") {
+		Some("the puzzle is not unlocked yet")
+	} else if first_line.starts_with("404 Not Found") {
+		Some("the puzzle doesn't exist")
+	} else {
+		None
 	}
 }
 
-pub fn get_input(year: u16, day: u8) -> String {
-	let filename = format!("./input/{}/day{:02}.txt", year, day);
+/// Download the input even if it is already cached.
+/// It's only cached when it looks like a valid input.
+pub fn fetch_input(year: u16, day: u8) -> Result<String, Error> {
+	let filename = input_path(year, day);
 	let p = Path::new(&filename);
 
-	if p.is_file() {
-		fs::read_to_string(filename)
-			.expect("Unable to read input file")
-			.trim_end()
-			.to_string()
-	} else {
-		// Building session cookie string
-		let mut session = String::from("session=");
-		let session_value = get_session();
-		session.push_str(&session_value);
+	// Fetch input
+	let bytes = client()?.input(year, day).map_err(|e| Error::Download(year, day, e.to_string()))?;
+	let text = normalize_input(&bytes);
+	if let Some(reason) = invalid_input(&text) {
+		return Err(Error::Download(year, day, reason.to_string()));
+	}
+
+	// Writing input inside file to avoid downloading input everytime
+	fs::create_dir_all(p.parent().unwrap())
+		.and_then(|_| fs::write(&filename, &text))
+		.map_err(|e| Error::Io(filename.clone(), e.to_string()))?;
+
+	Ok(text)
+}
 
-		// Fetch input
-		let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
-		let text = send_get(&session, &url).expect("Couldn't fetch input");
+/// Input of a day if it's already downloaded
+pub fn cached_input(year: u16, day: u8) -> Result<Option<String>, Error> {
+	let filename = input_path(year, day);
+	if !Path::new(&filename).is_file() {
+		return Ok(None);
+	}
 
-		// Writing input inside file to avoid downloading input everytime
-		fs::create_dir_all(p.parent().unwrap()).unwrap();
-		fs::write(filename, &text).unwrap();
+	let bytes = fs::read(&filename).map_err(|e| Error::Input(filename.clone(), e.to_string()))?;
+	let text = normalize_input(&bytes);
+	match invalid_input(&text) {
+		Some(reason) => Err(Error::Input(filename, format!("{reason}, download it again with `fetch --force`"))),
+		None => Ok(Some(text)),
+	}
+}
 
-		text
+pub fn get_input(year: u16, day: u8) -> Result<String, Error> {
+	match cached_input(year, day)? {
+		Some(text) => Ok(text),
+		None => fetch_input(year, day),
 	}
 }
 
+/// Read an input from a file, or from the standard input if `path` is `-`
+pub fn read_input_file(path: &str) -> io::Result<String> {
+	let content = if path == "-" {
+		let mut content = Vec::new();
+		io::stdin().read_to_end(&mut content)?;
+		content
+	} else {
+		fs::read(path)?
+	};
+
+	Ok(normalize_input(&content))
+}
+
+/// Convert a number of days since 1970-01-01 to a (year, month, day) date.
+/// See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+	let z = days + 719468;
+	let era = z.div_euclid(146097);
+	let doe = z.rem_euclid(146097);
+	let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+	let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+	(year, month, day)
+}
+
+/// Convert a (year, month, day) date to a number of days since 1970-01-01.
+/// See http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let yoe = year.rem_euclid(400);
+	let mp = (month as i64 + 9) % 12;
+	let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+	let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+	era * 146097 + doe - 719468
+}
+
+/// Time at which a puzzle is released, in seconds since 1970
+pub fn unlock_time(year: u16, day: u8) -> i64 {
+	days_from_civil(year as i64, 12, day as u32) * 86400 + 5 * 3600
+}
+
+/// Amount of puzzles of an event, there's only 12 of them since 2025
+pub fn event_days(year: u16) -> u8 {
+	if year >= 2025 { 12 } else { 25 }
+}
+
+/// Year of the last Advent of Code event, puzzles are released at midnight UTC-5
+pub fn current_event_year() -> u16 {
+	let secs = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.expect("System time is before 1970")
+		.as_secs() as i64;
+	event_year_at(secs)
+}
+
+fn event_year_at(secs: i64) -> u16 {
+	let (year, month, _) = civil_from_days((secs - 5 * 3600).div_euclid(86400));
+	if month == 12 { year as u16 } else { year as u16 - 1 }
+}
+
 pub fn colored_time(duration: Duration) -> ColoredString {
 	let s = format!("{duration:.2?}");
 	let ms = duration.as_millis();
@@ -97,3 +246,92 @@ pub fn colored_time(duration: Duration) -> ColoredString {
 		_ => s.red(),
 	}
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use std::ops::Deref;
+	use std::path::{Path, PathBuf};
+
+	use super::*;
+
+	/// Directory of a test in the temporary directory, removed when dropped
+	pub(crate) struct TempDir(PathBuf);
+
+	impl TempDir {
+		pub(crate) fn new(name: &str) -> Self {
+			let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+			let _ = std::fs::remove_dir_all(&dir);
+			Self(dir)
+		}
+	}
+
+	impl Deref for TempDir {
+		type Target = Path;
+
+		fn deref(&self) -> &Path {
+			&self.0
+		}
+	}
+
+	impl AsRef<Path> for TempDir {
+		fn as_ref(&self) -> &Path {
+			&self.0
+		}
+	}
+
+	impl From<&TempDir> for PathBuf {
+		fn from(dir: &TempDir) -> Self {
+			dir.0.clone()
+		}
+	}
+
+	impl Drop for TempDir {
+		fn drop(&mut self) {
+			let _ = std::fs::remove_dir_all(&self.0);
+		}
+	}
+
+	#[test]
+	fn dates() {
+		assert_eq!(civil_from_days(0), (1970, 1, 1));
+		assert_eq!(civil_from_days(20454), (2026, 1, 1));
+		assert_eq!(civil_from_days(20788), (2026, 12, 1));
+		assert_eq!(days_from_civil(1970, 1, 1), 0);
+		assert_eq!(days_from_civil(2026, 12, 1), 20788);
+		assert_eq!(unlock_time(2025, 1), 1764565200);
+	}
+
+	#[test]
+	fn event_year() {
+		// 2025-12-01 04:59 UTC, still the 30th of November in UTC-5
+		assert_eq!(event_year_at(1764565140), 2024);
+		// 2025-12-01 05:00 UTC
+		assert_eq!(event_year_at(1764565200), 2025);
+		// 2026-10-18
+		assert_eq!(event_year_at(1792281600), 2025);
+	}
+
+	#[test]
+	fn user() {
+		let page = r#"<div><p class="title"><a href="/">Advent of Code</a></p><div class="user">Alice <span class="star-count">42*</span></div></div>"#;
+		assert_eq!(user_name(page), Some("Alice".to_string()));
+		assert_eq!(user_name(r#"<div><p><a href="/2025/auth/login">[Log In]</a></p></div>"#), None);
+	}
+
+	#[test]
+	fn normalize() {
+		assert_eq!(normalize_input(b"\xef\xbb\xbf12 \r\n3  \r\n\r\n"), "12 \n3");
+		assert_eq!(normalize_input(b"\xff\xfea\0\r\0\n\0b\0"), "a\nb");
+		assert_eq!(normalize_input(b"a\rb\n"), "a\nb");
+	}
+
+	#[test]
+	fn invalid_inputs() {
+		assert_eq!(invalid_input("1122\n+5"), None);
+		assert!(invalid_input("").is_some());
+		assert!(invalid_input("<!DOCTYPE html>\n<html lang=\"en-us\">").is_some());
+		assert!(invalid_input("Puzzle inputs differ by user.  Please log in to get your puzzle input.").is_some());
+		assert!(invalid_input("Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.").is_some());
+		assert!(invalid_input("404 Not Found").is_some());
+	}
+}
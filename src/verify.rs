@@ -0,0 +1,322 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use colored::{ColoredString, Colorize};
+use serde_json::{Map, Value};
+
+use crate::answer::Answer;
+use crate::runner::{DayResult, Failure};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+	Correct,
+	Wrong { expected: String },
+	Unknown,
+}
+
+impl Verdict {
+	fn mark(&self) -> ColoredString {
+		match self {
+			Self::Correct => "✓".green(),
+			Self::Wrong { .. } => "✗".red(),
+			Self::Unknown => "?".normal(),
+		}
+	}
+}
+
+/// Known answers of a year, indexed by day
+type YearAnswers = BTreeMap<u8, [Option<String>; 2]>;
+
+/// Known answers of real inputs, stored as one JSON file per year:
+/// `{"01": {"part1": "...", "part2": "..."}}`
+pub struct AnswerStore {
+	dir: PathBuf,
+	years: HashMap<u16, YearAnswers>,
+	/// Years with newly recorded answers
+	changed: BTreeSet<u16>,
+}
+
+impl Default for AnswerStore {
+	fn default() -> Self {
+		Self::new("./answers")
+	}
+}
+
+impl AnswerStore {
+	pub fn new(dir: impl Into<PathBuf>) -> Self {
+		Self {
+			dir: dir.into(),
+			years: HashMap::new(),
+			changed: BTreeSet::new(),
+		}
+	}
+
+	pub fn dir(&self) -> &Path {
+		&self.dir
+	}
+
+	pub fn path(&self, year: u16) -> PathBuf {
+		self.dir.join(format!("{year}.json"))
+	}
+
+	/// Read the known answers of a year, if not done yet. The answers of years which aren't loaded
+	/// are unknown.
+	pub fn load(&mut self, year: u16) -> io::Result<()> {
+		if self.years.contains_key(&year) {
+			return Ok(());
+		}
+
+		let mut answers = YearAnswers::new();
+		match fs::read_to_string(self.path(year)) {
+			Ok(content) => {
+				let json: Value = serde_json::from_str(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+				for (day, parts) in json.as_object().into_iter().flatten() {
+					let Ok(day) = day.parse() else { continue };
+					let part = |key: &str| parts.get(key).and_then(Value::as_str).map(str::to_string);
+					answers.insert(day, [part("part1"), part("part2")]);
+				}
+			}
+			Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+			Err(e) => return Err(e),
+		}
+
+		self.years.insert(year, answers);
+		Ok(())
+	}
+
+	pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+		self.years.get(&year)?.get(&day)?[part as usize - 1].as_deref()
+	}
+
+	pub fn verify(&self, year: u16, day: u8, part: u8, answer: &Answer) -> Verdict {
+		check(self.get(year, day, part), &Ok(answer.clone()))
+	}
+
+	/// Verify both parts of a day, a failed part is wrong if its answer is known
+	pub fn verify_day(&self, result: &DayResult) -> [Verdict; 2] {
+		[
+			check(self.get(result.year, result.day, 1), &result.part1.answer),
+			check(self.get(result.year, result.day, 2), &result.part2.answer),
+		]
+	}
+
+	/// Keep the answer of a part, whose year must be loaded
+	pub fn record(&mut self, year: u16, day: u8, part: u8, answer: &Answer) {
+		if let Some(value) = answer.value() {
+			let answers = self.years.get_mut(&year).expect("answers of the year are loaded");
+			answers.entry(day).or_default()[part as usize - 1] = Some(value);
+			self.changed.insert(year);
+		}
+	}
+
+	/// Write the files of years having newly recorded answers
+	pub fn save(&self) -> io::Result<()> {
+		for year in &self.changed {
+			let mut json = Map::new();
+			for (day, parts) in &self.years[year] {
+				let mut obj = Map::new();
+				for (key, value) in ["part1", "part2"].iter().zip(parts) {
+					if let Some(value) = value {
+						obj.insert(key.to_string(), Value::String(value.clone()));
+					}
+				}
+				json.insert(format!("{day:02}"), Value::Object(obj));
+			}
+
+			fs::create_dir_all(&self.dir)?;
+			fs::write(self.path(*year), serde_json::to_string_pretty(&Value::Object(json))? + "\n")?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Compare the answer of a part with the expected one, a failed part is wrong if an answer is expected
+pub fn check(expected: Option<&str>, answer: &Result<Answer, Failure>) -> Verdict {
+	let Some(expected) = expected else {
+		return Verdict::Unknown;
+	};
+
+	match answer {
+		Ok(answer) => match answer.value() {
+			Some(value) if value == expected => Verdict::Correct,
+			Some(_) => Verdict::Wrong {
+				expected: expected.to_string(),
+			},
+			None => Verdict::Unknown,
+		},
+		Err(Failure::Unselected) => Verdict::Unknown,
+		Err(_) => Verdict::Wrong {
+			expected: expected.to_string(),
+		},
+	}
+}
+
+/// A day result along with the verdict of each part
+pub struct Verified<'a>(pub &'a DayResult, pub &'a [Verdict; 2]);
+
+impl Display for Verified<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let Self(result, verdicts) = self;
+		write!(f, "{result} ")?;
+		for (verdict, p) in verdicts.iter().zip(result.parts()) {
+			if p.answer != Err(Failure::Unselected) {
+				write!(f, " {}", verdict.mark())?;
+			}
+		}
+
+		match &result.parse_failure {
+			Some(Failure::Invalid(e)) => write!(f, "\n{e}")?,
+			Some(Failure::Unavailable(reason)) => write!(f, "\n{reason}")?,
+			_ => (),
+		}
+		Ok(())
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Regression {
+	year: u16,
+	day: u8,
+	part: u8,
+	got: String,
+	expected: String,
+}
+
+/// Verdicts gathered over a run
+#[derive(Debug, Default)]
+pub struct Summary {
+	pub correct: usize,
+	pub unknown: usize,
+	pub panicked: usize,
+	pub invalid: usize,
+	regressions: Vec<Regression>,
+}
+
+impl Summary {
+	pub fn add(&mut self, result: &DayResult, verdicts: &[Verdict; 2]) {
+		if result.has_panicked() {
+			self.panicked += 1;
+		}
+		if result.has_invalid_input() {
+			self.invalid += 1;
+		}
+
+		for (part, (verdict, p)) in verdicts.iter().zip(result.parts()).enumerate() {
+			if p.answer == Err(Failure::Unselected) {
+				continue;
+			}
+
+			match verdict {
+				Verdict::Correct => self.correct += 1,
+				Verdict::Unknown => self.unknown += 1,
+				Verdict::Wrong { expected } => self.regressions.push(Regression {
+					year: result.year,
+					day: result.day,
+					part: part as u8 + 1,
+					got: match &p.answer {
+						Ok(answer) => answer.to_string(),
+						Err(failure) => failure.to_string(),
+					},
+					expected: expected.clone(),
+				}),
+			}
+		}
+	}
+
+	pub fn wrong(&self) -> usize {
+		self.regressions.len()
+	}
+
+	pub fn is_success(&self) -> bool {
+		self.regressions.is_empty() && self.panicked == 0 && self.invalid == 0
+	}
+}
+
+impl Display for Summary {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"answers: {} correct, {} wrong, {} unknown",
+			self.correct,
+			self.wrong(),
+			self.unknown
+		)?;
+		if self.panicked > 0 {
+			write!(f, ", {} days panicked", self.panicked)?;
+		}
+		if self.invalid > 0 {
+			write!(f, ", {} days with an invalid input", self.invalid)?;
+		}
+		for r in &self.regressions {
+			write!(
+				f,
+				"\n  {} {} day {:02} part {}: got {}, expected {}",
+				"✗".red(),
+				r.year,
+				r.day,
+				r.part,
+				r.got,
+				r.expected
+			)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::tests::TempDir;
+
+	#[test]
+	fn verify_answers() {
+		let dir = TempDir::new("verify");
+		fs::create_dir_all(&dir).unwrap();
+		fs::write(dir.join("2024.json"), r#"{"01": {"part1": "11", "part2": "32"}}"#).unwrap();
+
+		let mut store = AnswerStore::new(&dir);
+		store.load(2024).unwrap();
+		assert_eq!(store.verify(2024, 1, 1, &Answer::Integer(11)), Verdict::Correct);
+		assert_eq!(
+			store.verify(2024, 1, 2, &Answer::Integer(31)),
+			Verdict::Wrong {
+				expected: "32".to_string()
+			}
+		);
+		assert_eq!(store.verify(2024, 2, 1, &Answer::Integer(2)), Verdict::Unknown);
+		assert_eq!(store.verify(2023, 1, 1, &Answer::Integer(2)), Verdict::Unknown);
+		assert_eq!(store.verify(2024, 1, 1, &Answer::None), Verdict::Unknown);
+	}
+
+	#[test]
+	fn invalid_answers() {
+		let dir = TempDir::new("invalid-answers");
+		fs::create_dir_all(&dir).unwrap();
+		fs::write(dir.join("2024.json"), "<html>").unwrap();
+
+		let mut store = AnswerStore::new(&dir);
+		assert_eq!(store.load(2024).map_err(|e| e.kind()), Err(io::ErrorKind::InvalidData));
+		assert_eq!(store.load(2023).map_err(|e| e.kind()), Ok(()));
+	}
+
+	#[test]
+	fn record_answers() {
+		let dir = TempDir::new("record");
+
+		let mut store = AnswerStore::new(&dir);
+		store.load(2024).unwrap();
+		store.record(2024, 3, 1, &Answer::Integer(5));
+		store.record(2024, 3, 2, &Answer::Text("abc".to_string()));
+		store.record(2024, 25, 2, &Answer::None);
+		store.save().unwrap();
+
+		let mut store = AnswerStore::new(&dir);
+		store.load(2024).unwrap();
+		assert_eq!(store.get(2024, 3, 1), Some("5"));
+		assert_eq!(store.get(2024, 3, 2), Some("abc"));
+		assert_eq!(store.get(2024, 25, 2), None);
+	}
+}
@@ -43,8 +43,8 @@ pub fn part2(input: &[Dimension]) -> u32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE1: &str = "2x3x4";
-	const EXAMPLE2: &str = "1x1x10";
+	const EXAMPLE1: &str = include_str!("../../data/examples/2015/day02-1.txt").trim_ascii_end();
+	const EXAMPLE2: &str = include_str!("../../data/examples/2015/day02-2.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
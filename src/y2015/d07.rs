@@ -89,14 +89,7 @@ pub fn part2(input: &HashMap<String, Instruction>) -> u16 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "123 -> x
-456 -> y
-x AND y -> d
-x OR y -> e
-x LSHIFT 2 -> f
-y RSHIFT 2 -> g
-NOT x -> h
-NOT y -> a";
+	const EXAMPLE: &str = include_str!("../../data/examples/2015/day07-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
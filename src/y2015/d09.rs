@@ -65,9 +65,7 @@ pub fn part2(input: &Cities) -> u32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "London to Dublin = 464
-London to Belfast = 518
-Dublin to Belfast = 141";
+	const EXAMPLE: &str = include_str!("../../data/examples/2015/day09-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -89,18 +89,7 @@ pub fn part2(input: &Family) -> i32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "Alice would gain 54 happiness units by sitting next to Bob.
-Alice would lose 79 happiness units by sitting next to Carol.
-Alice would lose 2 happiness units by sitting next to David.
-Bob would gain 83 happiness units by sitting next to Alice.
-Bob would lose 7 happiness units by sitting next to Carol.
-Bob would lose 63 happiness units by sitting next to David.
-Carol would lose 62 happiness units by sitting next to Alice.
-Carol would gain 60 happiness units by sitting next to Bob.
-Carol would gain 55 happiness units by sitting next to David.
-David would gain 46 happiness units by sitting next to Alice.
-David would lose 7 happiness units by sitting next to Bob.
-David would gain 41 happiness units by sitting next to Carol.";
+	const EXAMPLE: &str = include_str!("../../data/examples/2015/day13-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example() {
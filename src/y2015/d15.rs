@@ -91,8 +91,7 @@ pub fn part2(input: &[Ingredient]) -> i64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
-Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
+	const EXAMPLE: &str = include_str!("../../data/examples/2015/day15-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -77,13 +77,7 @@ pub fn part2(input: &Machine) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "H => HO
-H => OH
-O => HH
-e => H
-e => O
-
-HOHOHO";
+	const EXAMPLE: &str = include_str!("../../data/examples/2015/day19-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -1,5 +1,7 @@
 use regex::Regex;
 
+use crate::answer::Answer;
+
 pub fn parse_input(input: &str) -> (u32, u32) {
 	let re = Regex::new(r"To continue, please consult the code grid in the manual.  Enter the code at row (\d+), column (\d+).").unwrap();
 	let caps = re.captures(input).unwrap();
@@ -24,8 +26,8 @@ pub fn part1(input: &(u32, u32)) -> u64 {
 	0
 }
 
-pub fn part2(_: &(u32, u32)) -> usize {
-	0
+pub fn part2(_: &(u32, u32)) -> Answer {
+	Answer::None
 }
 
 #[cfg(test)]
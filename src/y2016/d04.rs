@@ -85,10 +85,7 @@ pub fn part2(input: &[Room]) -> u32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "aaaaa-bbb-z-y-x-123[abxyz]
-a-b-c-d-e-f-g-h-987[abcde]
-not-a-real-room-404[oarel]
-totally-real-room-200[decoy]";
+	const EXAMPLE: &str = include_str!("../../data/examples/2016/day04-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
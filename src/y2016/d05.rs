@@ -63,7 +63,7 @@ pub fn part1(input: &str) -> String {
 
 		if answers.len() >= 8 {
 			alive.store(false, std::sync::atomic::Ordering::Relaxed);
-			answers.sort_by(|a, b| a.index.cmp(&b.index));
+			answers.sort_by_key(|a| a.index);
 
 			while let Some(handle) = handles.pop() {
 				handle.join().unwrap();
@@ -38,22 +38,7 @@ pub fn part2(input: &[String]) -> String {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "eedadn
-drvtee
-eandsr
-raavrd
-atevrs
-tsrnev
-sdttsa
-rasrtv
-nssdts
-ntnada
-svetve
-tesnvt
-vntsnd
-vrdear
-dvrsen
-enarar";
+	const EXAMPLE: &str = include_str!("../../data/examples/2016/day06-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
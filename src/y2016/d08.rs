@@ -1,5 +1,6 @@
 use std::fmt::{Display, Write};
 
+use crate::answer::Answer;
 use crate::collections::{Grid, Point};
 
 #[derive(Debug)]
@@ -107,13 +108,13 @@ pub fn part1(input: &[Operation]) -> usize {
 	screen.count_lit()
 }
 
-pub fn part2(input: &[Operation]) -> String {
+pub fn part2(input: &[Operation]) -> Answer {
 	let mut screen = Screen::new(50, 6);
 	for op in input {
 		screen.exec(op);
 	}
 
-	format!("\n{screen}")
+	Answer::Image(screen.to_string().trim_end().to_string())
 }
 
 #[cfg(test)]
@@ -1,16 +1,10 @@
-use crate::run_day;
+use crate::solution;
+use crate::solution::Solution;
 
 mod d01;
 mod d02;
 
-pub fn run(day: u8) {
-	match day {
-		1 => run_day!(y2017, d01),
-		2 => run_day!(y2017, d02),
-		_ => unimplemented!(),
-	}
-}
-
-pub fn run_all() {
-	(1..=2).for_each(run)
-}
+pub const SOLUTIONS: &[&dyn Solution] = &[
+	solution!(y2017, d01),
+	solution!(y2017, d02),
+];
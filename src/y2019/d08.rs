@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::answer::Answer;
+
 struct Image<'a> {
 	layers: Vec<&'a [u8]>,
 	width: usize,
@@ -58,20 +60,21 @@ pub fn part1(input: &[u8]) -> usize {
 		.1
 }
 
-pub fn part2(input: &[u8]) -> String {
+pub fn part2(input: &[u8]) -> Answer {
 	let mut res = String::new();
 	let img = Image::new(input, 25, 6);
 	let decoded = img.decode();
 
-	res.push('\n');
-	for line in decoded.chunks(img.width) {
+	for (i, line) in decoded.chunks(img.width).enumerate() {
+		if i > 0 {
+			res.push('\n');
+		}
 		for c in line {
 			res.push(if c == &1 { 'X' } else { ' ' });
 		}
-		res.push('\n');
 	}
 
-	res
+	Answer::Image(res)
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
-use crate::run_day;
+use crate::solution;
+use crate::solution::Solution;
 
-mod intcode;
+pub(crate) mod intcode;
 
 mod d01;
 mod d02;
@@ -13,22 +14,15 @@ mod d08;
 mod d09;
 mod d10;
 
-pub fn run(day: u8) {
-	match day {
-		1 => run_day!(y2019, d01),
-		2 => run_day!(y2019, d02),
-		3 => run_day!(y2019, d03),
-		4 => run_day!(y2019, d04),
-		5 => run_day!(y2019, d05),
-		6 => run_day!(y2019, d06),
-		7 => run_day!(y2019, d07),
-		8 => run_day!(y2019, d08),
-		9 => run_day!(y2019, d09),
-		10 => run_day!(y2019, d10),
-		_ => unimplemented!(),
-	}
-}
-
-pub fn run_all() {
-	(1..=10).for_each(run)
-}
+pub const SOLUTIONS: &[&dyn Solution] = &[
+	solution!(y2019, d01),
+	solution!(y2019, d02),
+	solution!(y2019, d03),
+	solution!(y2019, d04),
+	solution!(y2019, d05),
+	solution!(y2019, d06),
+	solution!(y2019, d07),
+	solution!(y2019, d08),
+	solution!(y2019, d09),
+	solution!(y2019, d10),
+];
@@ -28,12 +28,7 @@ pub fn part2(input: &[i32]) -> i32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "1721
-979
-366
-299
-675
-1456";
+	const EXAMPLE: &str = include_str!("../../data/examples/2020/day01-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -53,9 +53,7 @@ pub fn part2(input: &[Password]) -> u32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "1-3 a: abcde
-1-3 b: cdefg
-2-9 c: ccccccccc";
+	const EXAMPLE: &str = include_str!("../../data/examples/2020/day02-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
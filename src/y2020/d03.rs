@@ -43,17 +43,7 @@ pub fn part2(input: &Map) -> u32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "..##.......
-#...#...#..
-.#....#..#.
-..#.#...#.#
-.#...##..#.
-..#.##.....
-.#.#.#....#
-.#........#
-#.##...#...
-#...##....#
-.#..#...#.#";
+	const EXAMPLE: &str = include_str!("../../data/examples/2020/day03-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
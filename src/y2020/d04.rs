@@ -219,19 +219,7 @@ pub fn part2(input: &[HashMap<String, String>]) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
-byr:1937 iyr:2017 cid:147 hgt:183cm
-
-iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
-hcl:#cfa07d byr:1929
-
-hcl:#ae17e1 iyr:2013
-eyr:2024
-ecl:brn pid:760753108 byr:1931
-hgt:179cm
-
-hcl:#cfa07d eyr:2025 pid:166559648
-iyr:2011 ecl:brn hgt:59in";
+	const EXAMPLE: &str = include_str!("../../data/examples/2020/day04-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -1,4 +1,5 @@
-use crate::run_day;
+use crate::solution;
+use crate::solution::Solution;
 
 mod d01;
 mod d02;
@@ -6,17 +7,10 @@ mod d03;
 mod d04;
 mod d05;
 
-pub fn run(day: u8) {
-	match day {
-		1 => run_day!(y2020, d01),
-		2 => run_day!(y2020, d02),
-		3 => run_day!(y2020, d03),
-		4 => run_day!(y2020, d04),
-		5 => run_day!(y2020, d05),
-		_ => unimplemented!(),
-	}
-}
-
-pub fn run_all() {
-	(1..=5).for_each(run)
-}
+pub const SOLUTIONS: &[&dyn Solution] = &[
+	solution!(y2020, d01),
+	solution!(y2020, d02),
+	solution!(y2020, d03),
+	solution!(y2020, d04),
+	solution!(y2020, d05),
+];
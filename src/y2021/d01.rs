@@ -26,16 +26,7 @@ pub fn part2(input: &[i32]) -> i32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "199
-200
-208
-210
-200
-207
-240
-269
-260
-263";
+	const EXAMPLE: &str = include_str!("../../data/examples/2021/day01-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
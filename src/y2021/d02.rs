@@ -48,12 +48,7 @@ pub fn part2(input: &[(String, i32)]) -> i32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "forward 5
-down 5
-forward 8
-up 3
-down 8
-forward 2";
+	const EXAMPLE: &str = include_str!("../../data/examples/2021/day02-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
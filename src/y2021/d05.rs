@@ -69,16 +69,7 @@ pub fn part2(input: &[(u32, u32, u32, u32)]) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "0,9 -> 5,9
-8,0 -> 0,8
-9,4 -> 3,4
-2,2 -> 2,1
-7,0 -> 7,4
-6,4 -> 2,0
-0,9 -> 2,9
-3,4 -> 1,4
-0,0 -> 8,8
-5,5 -> 8,2";
+	const EXAMPLE: &str = include_str!("../../data/examples/2021/day05-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -67,7 +67,7 @@ pub fn part2(input: &[u32]) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "3,4,3,1,2";
+	const EXAMPLE: &str = include_str!("../../data/examples/2021/day06-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -31,7 +31,7 @@ pub fn part2(input: &[i32]) -> i32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "16,1,2,0,4,2,7,1,2,14";
+	const EXAMPLE: &str = include_str!("../../data/examples/2021/day07-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -84,11 +84,7 @@ pub fn part2(input: &[Vec<u8>]) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "2199943210
-3987894921
-9856789892
-8767896789
-9899965678";
+	const EXAMPLE: &str = include_str!("../../data/examples/2021/day09-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -102,16 +102,7 @@ pub fn part2(input: &[Line]) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "[({(<(())[]>[[{[]{<()<>>
-[(()[<>])]({[<{<<[]>>(
-{([(<{}[<>[]}>{[]{[(<()>
-(((({<>}<{<{<>}{[]{[]{}
-[[<[([]))<([[{}[[()]]]
-[{[{({}]{}}([{[{{{}}([]
-{<[[]]>}<{[{[{[]{()[[[]
-[<(<(<(<{}))><([]([]()
-<{([([[(<>()){}]>(<<{{
-<{([{{}}[<[[[<>{}]]]>[]]";
+	const EXAMPLE: &str = include_str!("../../data/examples/2021/day10-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
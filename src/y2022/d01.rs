@@ -24,20 +24,7 @@ pub fn part2(input: &[Vec<i32>]) -> i32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "1000
-2000
-3000
-
-4000
-
-5000
-6000
-
-7000
-8000
-9000
-
-10000";
+	const EXAMPLE: &str = include_str!("../../data/examples/2022/day01-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -109,9 +109,7 @@ pub fn part2(input: &[Round]) -> i32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "A Y
-B X
-C Z";
+	const EXAMPLE: &str = include_str!("../../data/examples/2022/day02-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -50,12 +50,7 @@ pub fn part2(input: &[String]) -> i32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw";
+	const EXAMPLE: &str = include_str!("../../data/examples/2022/day03-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
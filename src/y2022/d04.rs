@@ -38,12 +38,7 @@ pub fn part2(input: &[(RangeInclusive<i32>, RangeInclusive<i32>)]) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8";
+	const EXAMPLE: &str = include_str!("../../data/examples/2022/day04-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
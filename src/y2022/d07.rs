@@ -136,29 +136,7 @@ pub fn part2(input: &[Command]) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k";
+	const EXAMPLE: &str = include_str!("../../data/examples/2022/day07-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
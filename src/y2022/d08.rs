@@ -168,11 +168,7 @@ pub fn part2(input: &Grid<Number>) -> u32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "30373
-25512
-65332
-33549
-35390";
+	const EXAMPLE: &str = include_str!("../../data/examples/2022/day08-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_get() {
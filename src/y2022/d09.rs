@@ -160,23 +160,9 @@ pub fn part2(input: &[Instruction]) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2";
-
-	const EXAMPLE2: &str = "R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20";
+	const EXAMPLE: &str = include_str!("../../data/examples/2022/day09-1.txt").trim_ascii_end();
+
+	const EXAMPLE2: &str = include_str!("../../data/examples/2022/day09-2.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -1,3 +1,5 @@
+use crate::answer::Answer;
+
 #[derive(Debug)]
 pub enum Instruction {
 	Noop,
@@ -52,14 +54,14 @@ pub fn part1(input: &[Instruction]) -> i32 {
 	res
 }
 
-pub fn part2(input: &[Instruction]) -> String {
+pub fn part2(input: &[Instruction]) -> Answer {
 	let mut res = String::new();
 	let mut x = 1;
 	let cycles = generate_cycles(input);
 	let mut iter = cycles.iter().cycle();
 
 	for i in 0..240 {
-		if i % 40 == 0 {
+		if i > 0 && i % 40 == 0 {
 			res.push('\n')
 		}
 
@@ -72,7 +74,7 @@ pub fn part2(input: &[Instruction]) -> String {
 		x += iter.next().unwrap();
 	}
 
-	res
+	Answer::Image(res)
 }
 
 #[cfg(test)]
@@ -233,7 +235,7 @@ noop";
 
 	#[test]
 	fn example_part2() {
-		const EXPECTED: &str = "
+		const EXPECTED: &str = "\
 ██  ██  ██  ██  ██  ██  ██  ██  ██  ██  
 ███   ███   ███   ███   ███   ███   ███ 
 ████    ████    ████    ████    ████    
@@ -241,6 +243,6 @@ noop";
 ██████      ██████      ██████      ████
 ███████       ███████       ███████     ";
 
-		assert_eq!(part2(&parse_input(EXAMPLE)), EXPECTED.to_string());
+		assert_eq!(part2(&parse_input(EXAMPLE)), Answer::Image(EXPECTED.to_string()));
 	}
 }
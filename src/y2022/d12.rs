@@ -166,11 +166,7 @@ pub fn part2(input: &Grid<Cell>) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "Sabqponm
-abcryxxl
-accszExk
-acctuvwj
-abdefghi";
+	const EXAMPLE: &str = include_str!("../../data/examples/2022/day12-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -1,4 +1,5 @@
-use crate::run_day;
+use crate::solution;
+use crate::solution::Solution;
 
 mod d01;
 mod d02;
@@ -13,24 +14,17 @@ mod d10;
 mod d11;
 mod d12;
 
-pub fn run(day: u8) {
-	match day {
-		1 => run_day!(y2022, d01),
-		2 => run_day!(y2022, d02),
-		3 => run_day!(y2022, d03),
-		4 => run_day!(y2022, d04),
-		5 => run_day!(y2022, d05),
-		6 => run_day!(y2022, d06),
-		7 => run_day!(y2022, d07),
-		8 => run_day!(y2022, d08),
-		9 => run_day!(y2022, d09),
-		10 => run_day!(y2022, d10),
-		11 => run_day!(y2022, d11),
-		12 => run_day!(y2022, d12),
-		_ => unimplemented!(),
-	}
-}
-
-pub fn run_all() {
-	(1..=12).for_each(run)
-}
+pub const SOLUTIONS: &[&dyn Solution] = &[
+	solution!(y2022, d01),
+	solution!(y2022, d02),
+	solution!(y2022, d03),
+	solution!(y2022, d04),
+	solution!(y2022, d05),
+	solution!(y2022, d06),
+	solution!(y2022, d07),
+	solution!(y2022, d08),
+	solution!(y2022, d09),
+	solution!(y2022, d10),
+	solution!(y2022, d11),
+	solution!(y2022, d12),
+];
@@ -61,18 +61,9 @@ pub fn part2(input: &[String]) -> u32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day01-1.txt").trim_ascii_end();
 
-	const EXAMPLE2: &str = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen";
+	const EXAMPLE2: &str = include_str!("../../data/examples/2023/day01-2.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
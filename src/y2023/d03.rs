@@ -168,29 +168,9 @@ pub fn part2(input: &Grid<Element>) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..";
-
-	const EXAMPLE2: &str = "12.......*..
-+.........34
-.......-12..
-..78........
-..*....60...
-78.........9
-.5.....23..$
-8...90*12...
-............
-2.2......12.
-.*.........*
-1.1..503+.56";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day03-1.txt").trim_ascii_end();
+
+	const EXAMPLE2: &str = include_str!("../../data/examples/2023/day03-2.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -43,11 +43,7 @@ pub fn part1(input: &[Card]) -> u32 {
 
 			let count = winning_numbers.intersection(&numbers).count() as u32;
 
-			if count == 0 {
-				0
-			} else {
-				2_u32.pow(count - 1)
-			}
+			if count == 0 { 0 } else { 2_u32.pow(count - 1) }
 		})
 		.sum()
 }
@@ -76,12 +72,7 @@ pub fn part2(input: &[Card]) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day04-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
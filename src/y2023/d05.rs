@@ -31,11 +31,7 @@ impl ConvertLine {
 		let sub_start = src_start.max(range.start);
 		let sub_end = src_end.min(range.end);
 
-		if sub_start < sub_end {
-			Some(sub_start..sub_end)
-		} else {
-			None
-		}
+		if sub_start < sub_end { Some(sub_start..sub_end) } else { None }
 	}
 }
 
@@ -150,39 +146,7 @@ pub fn part2(input: &Almanac) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
-
-humidity-to-location map:
-60 56 37
-56 93 4";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day05-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -62,8 +62,7 @@ pub fn part2(input: &Races) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "Time:      7  15   30
-Distance:  9  40  200";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day06-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -201,11 +201,7 @@ pub fn part2(input: &[Hand]) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day07-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
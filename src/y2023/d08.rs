@@ -112,32 +112,11 @@ pub fn part2(input: &Documents) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "RL
-
-AAA = (BBB, CCC)
-BBB = (DDD, EEE)
-CCC = (ZZZ, GGG)
-DDD = (DDD, DDD)
-EEE = (EEE, EEE)
-GGG = (GGG, GGG)
-ZZZ = (ZZZ, ZZZ)";
-
-	const EXAMPLE2: &str = "LLR
-
-AAA = (BBB, BBB)
-BBB = (AAA, ZZZ)
-ZZZ = (ZZZ, ZZZ)";
-
-	const EXAMPLE3: &str = "LR
-
-11A = (11B, XXX)
-11B = (XXX, 11Z)
-11Z = (11B, XXX)
-22A = (22B, XXX)
-22B = (22C, 22C)
-22C = (22Z, 22Z)
-22Z = (22B, 22B)
-XXX = (XXX, XXX)";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day08-1.txt").trim_ascii_end();
+
+	const EXAMPLE2: &str = include_str!("../../data/examples/2023/day08-2.txt").trim_ascii_end();
+
+	const EXAMPLE3: &str = include_str!("../../data/examples/2023/day08-3.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
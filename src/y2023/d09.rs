@@ -27,9 +27,7 @@ pub fn part2(input: &[Vec<i64>]) -> i64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day09-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -160,71 +160,21 @@ pub fn part2(input: &Grid<Tile>) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = ".....
-.S-7.
-.|.|.
-.L-J.
-.....";
-
-	const EXAMPLE2: &str = "..F7.
-.FJ|.
-SJ.L7
-|F--J
-LJ...";
-
-	const EXAMPLE3: &str = "-L|F7
-7S-7|
-L|7||
--L-J|
-L|-JF";
-
-	const EXAMPLE4: &str = "7-F7-
-.FJ|7
-SJLL7
-|F--J
-LJ.LJ";
-
-	const EXAMPLE5: &str = "...........
-.S-------7.
-.|F-----7|.
-.||.....||.
-.||.....||.
-.|L-7.F-J|.
-.|..|.|..|.
-.L--J.L--J.
-...........";
-
-	const EXAMPLE6: &str = "..........
-.S------7.
-.|F----7|.
-.||....||.
-.||....||.
-.|L-7F-J|.
-.|..||..|.
-.L--JL--J.
-..........";
-
-	const EXAMPLE7: &str = ".F----7F7F7F7F-7....
-.|F--7||||||||FJ....
-.||.FJ||||||||L7....
-FJL7L7LJLJ||LJ.L-7..
-L--J.L7...LJS7F-7L7.
-....F-J..F7FJ|L7L7L7
-....L7.F7||L7|.L7L7|
-.....|FJLJ|FJ|F7|.LJ
-....FJL-7.||.||||...
-....L---J.LJ.LJLJ...";
-
-	const EXAMPLE8: &str = "FF7FSF7F7F7F7F7F---7
-L|LJ||||||||||||F--J
-FL-7LJLJ||||||LJL-77
-F--JF--7||LJLJ7F7FJ-
-L---JF-JLJ.||-FJLJJ7
-|F|F-JF---7F7-L7L|7|
-|FFJF7L7F-JF7|JL---7
-7-L-JL7||F7|L7F-7F7|
-L.L7LFJ|||||FJL7||LJ
-L7JLJL-JLJLJL--JLJ.L";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day10-1.txt").trim_ascii_end();
+
+	const EXAMPLE2: &str = include_str!("../../data/examples/2023/day10-2.txt").trim_ascii_end();
+
+	const EXAMPLE3: &str = include_str!("../../data/examples/2023/day10-3.txt").trim_ascii_end();
+
+	const EXAMPLE4: &str = include_str!("../../data/examples/2023/day10-4.txt").trim_ascii_end();
+
+	const EXAMPLE5: &str = include_str!("../../data/examples/2023/day10-5.txt").trim_ascii_end();
+
+	const EXAMPLE6: &str = include_str!("../../data/examples/2023/day10-6.txt").trim_ascii_end();
+
+	const EXAMPLE7: &str = include_str!("../../data/examples/2023/day10-7.txt").trim_ascii_end();
+
+	const EXAMPLE8: &str = include_str!("../../data/examples/2023/day10-8.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
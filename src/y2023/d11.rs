@@ -53,16 +53,7 @@ pub fn part2(input: &[Position]) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day11-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
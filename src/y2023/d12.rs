@@ -114,12 +114,7 @@ pub fn part2(input: &[Row]) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day12-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
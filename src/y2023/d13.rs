@@ -57,21 +57,7 @@ pub fn part2(input: &[Grid]) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day13-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -137,16 +137,7 @@ pub fn part2(input: &Grid) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day14-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -54,7 +54,7 @@ pub fn part2(input: &[String]) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day15-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -135,19 +135,7 @@ pub fn part2(input: &Grid<Number>) -> u32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "2413432311323
-3215453535623
-3255245654254
-3446585845452
-4546657867536
-1438598798454
-4457876987766
-3637877979653
-4654967986887
-4564679986453
-1224686865563
-2546548887735
-4322674655533";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day17-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
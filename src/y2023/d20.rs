@@ -338,17 +338,9 @@ pub fn part2(input: &HashMap<String, Module>) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "broadcaster -> a, b, c
-%a -> b
-%b -> c
-%c -> inv
-&inv -> a";
-
-	const EXAMPLE2: &str = "broadcaster -> a
-%a -> inv, con
-&inv -> b
-%b -> con
-&con -> output";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day20-1.txt").trim_ascii_end();
+
+	const EXAMPLE2: &str = include_str!("../../data/examples/2023/day20-2.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
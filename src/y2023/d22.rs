@@ -15,7 +15,7 @@ struct Brick {
 impl Tower {
 	fn sort_by_height(&self) -> Vec<Brick> {
 		let mut res = self.bricks.clone();
-		res.sort_by(|a, b| a.start_cube.2.cmp(&b.start_cube.2));
+		res.sort_by_key(|a| a.start_cube.2);
 		res
 	}
 
@@ -149,18 +149,9 @@ pub fn part2(input: &Tower) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "1,0,1~1,2,1
-0,0,2~2,0,2
-0,2,3~2,2,3
-0,0,4~0,2,4
-2,0,5~2,2,5
-0,1,6~2,1,6
-1,1,8~1,1,9";
-
-	const EXAMPLE2: &str = "0,0,1~0,1,1
-1,1,1~1,1,1
-0,0,2~0,0,2
-0,1,2~1,1,2";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day22-1.txt").trim_ascii_end();
+
+	const EXAMPLE2: &str = include_str!("../../data/examples/2023/day22-2.txt").trim_ascii_end();
 
 	#[test]
 	fn test_is_supporting() {
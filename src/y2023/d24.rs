@@ -164,11 +164,7 @@ pub fn part2(input: &[Hailstone]) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "19, 13, 30 @ -2,  1, -2
-18, 19, 22 @ -1, -1, -2
-20, 25, 34 @ -2, -2, -4
-12, 31, 28 @ -1, -2, -1
-20, 19, 15 @  1, -5, -3";
+	const EXAMPLE: &str = include_str!("../../data/examples/2023/day24-1.txt").trim_ascii_end();
 
 	#[test]
 	fn test_intersect() {
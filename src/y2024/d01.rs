@@ -47,12 +47,7 @@ pub fn part2(input: &[(u64, u64)]) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day01-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
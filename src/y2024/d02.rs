@@ -6,11 +6,7 @@ enum Level {
 
 impl Level {
 	fn new(a: u64, b: u64) -> Self {
-		if a < b {
-			Self::Increase(b - a)
-		} else {
-			Self::Decrease(a - b)
-		}
+		if a < b { Self::Increase(b - a) } else { Self::Decrease(a - b) }
 	}
 
 	fn correct_value(&self) -> bool {
@@ -67,12 +63,7 @@ pub fn part2(input: &[Vec<u64>]) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day02-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
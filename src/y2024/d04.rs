@@ -55,16 +55,7 @@ pub fn part2(input: &Grid<char>) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day04-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -88,11 +88,7 @@ pub fn part2(input: &Input) -> u32 {
 				}
 			}
 
-			if modified {
-				Some(sorted[p.len() / 2])
-			} else {
-				None
-			}
+			if modified { Some(sorted[p.len() / 2]) } else { None }
 		})
 		.sum()
 }
@@ -101,34 +97,7 @@ pub fn part2(input: &Input) -> u32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "47|53
-97|13
-97|61
-97|47
-75|29
-61|13
-75|53
-29|13
-97|29
-53|29
-61|53
-97|53
-61|29
-47|13
-75|47
-97|75
-47|61
-75|61
-47|29
-75|13
-53|13
-
-75,47,61,53,29
-97,61,53,29,13
-75,29,13
-75,97,47,61,53
-61,13,29
-97,13,75,29,47";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day05-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
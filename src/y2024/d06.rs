@@ -121,28 +121,14 @@ fn bruteforce2(input: &Grid<Cell>) -> usize {
 }
 
 pub fn part2(input: &Grid<Cell>) -> usize {
-	if input.height <= 10 && input.width <= 10 {
-		bruteforce2(input)
-	} else {
-		// TODO: even the "optimized" bruteforce takes to much time
-		0
-	}
+	bruteforce2(input)
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day06-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
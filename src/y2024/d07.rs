@@ -55,15 +55,7 @@ pub fn part2(input: &[Equation]) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day07-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
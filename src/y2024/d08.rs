@@ -85,18 +85,7 @@ pub fn part2(input: &Grid<char>) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "............
-........0...
-.....0......
-.......0....
-....0.......
-......A.....
-............
-............
-........A...
-.........A..
-............
-............";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day08-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
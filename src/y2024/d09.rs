@@ -208,7 +208,7 @@ pub fn part2(input: &str) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "2333133121414131402";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day09-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -7,11 +7,7 @@ pub struct Height(u32);
 
 impl From<char> for Height {
 	fn from(value: char) -> Self {
-		if value == '.' {
-			Self(1000)
-		} else {
-			Self(value as u32 - 48)
-		}
+		if value == '.' { Self(1000) } else { Self(value as u32 - 48) }
 	}
 }
 
@@ -61,14 +57,7 @@ pub fn part2(input: &Grid<Height>) -> u32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "89010123
-78121874
-87430965
-96549874
-45678903
-32019012
-01329801
-10456732";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day10-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -132,16 +132,7 @@ pub fn part2(input: &Grid<char>) -> u32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "RRRRIICCFF
-RRRRIICCCF
-VVRRRCCFFF
-VVRCCCJFFF
-VVVVCJJCFE
-VVIVCCJJEE
-VVIIICJJEE
-MIIIIIJJEE
-MIIISIJEEE
-MMMISSJEEE";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day12-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -126,11 +126,7 @@ pub fn part2(input: &Computer) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "Register A: 729
-Register B: 0
-Register C: 0
-
-Program: 0,1,5,4,3,0";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day17-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
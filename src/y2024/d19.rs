@@ -74,16 +74,7 @@ pub fn part2(input: &Towels) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "r, wr, b, g, bwu, rb, gb, br
-
-brwrr
-bggr
-gbbr
-rrbgbr
-ubwu
-bwurrg
-brgr
-bbrgwb";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day19-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -190,11 +190,7 @@ pub fn part2(input: &[String]) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "029A
-980A
-179A
-456A
-379A";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day21-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
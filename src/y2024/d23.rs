@@ -126,38 +126,7 @@ pub fn part2(input: &[(String, String)]) -> String {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "kh-tc
-qp-kh
-de-cg
-ka-co
-yn-aq
-qp-ub
-cg-tb
-vc-aq
-tb-ka
-wh-tc
-yn-cg
-kh-ub
-ta-co
-de-co
-tc-td
-tb-wq
-wh-td
-ta-ka
-td-qp
-aq-cg
-wq-ub
-ub-vc
-de-ta
-wq-aq
-wq-vc
-wh-yn
-ka-de
-kh-ta
-co-tc
-wh-qp
-tb-vc
-td-yn";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day23-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
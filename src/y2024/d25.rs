@@ -1,3 +1,5 @@
+use crate::answer::Answer;
+
 #[derive(Debug)]
 pub struct Lock([u8; 5]);
 
@@ -46,53 +48,15 @@ pub fn part1(input: &(Vec<Lock>, Vec<Key>)) -> usize {
 	res
 }
 
-pub fn part2(_: &(Vec<Lock>, Vec<Key>)) -> usize {
-	0
+pub fn part2(_: &(Vec<Lock>, Vec<Key>)) -> Answer {
+	Answer::None
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "#####
-.####
-.####
-.####
-.#.#.
-.#...
-.....
-
-#####
-##.##
-.#.##
-...##
-...#.
-...#.
-.....
-
-.....
-#....
-#....
-#...#
-#.#.#
-#.###
-#####
-
-.....
-.....
-#.#..
-###..
-###.#
-###.#
-#####
-
-.....
-.....
-.....
-#....
-#.#..
-#.#.#
-#####";
+	const EXAMPLE: &str = include_str!("../../data/examples/2024/day25-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
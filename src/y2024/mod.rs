@@ -1,4 +1,5 @@
-use crate::run_day;
+use crate::solution;
+use crate::solution::Solution;
 
 mod d01;
 mod d02;
@@ -26,37 +27,30 @@ mod d23;
 mod d24;
 mod d25;
 
-pub fn run(day: u8) {
-	match day {
-		1 => run_day!(y2024, d01),
-		2 => run_day!(y2024, d02),
-		3 => run_day!(y2024, d03),
-		4 => run_day!(y2024, d04),
-		5 => run_day!(y2024, d05),
-		6 => run_day!(y2024, d06),
-		7 => run_day!(y2024, d07),
-		8 => run_day!(y2024, d08),
-		9 => run_day!(y2024, d09),
-		10 => run_day!(y2024, d10),
-		11 => run_day!(y2024, d11),
-		12 => run_day!(y2024, d12),
-		13 => run_day!(y2024, d13),
-		14 => run_day!(y2024, d14),
-		15 => run_day!(y2024, d15),
-		16 => run_day!(y2024, d16),
-		17 => run_day!(y2024, d17),
-		18 => run_day!(y2024, d18),
-		19 => run_day!(y2024, d19),
-		20 => run_day!(y2024, d20),
-		21 => run_day!(y2024, d21),
-		22 => run_day!(y2024, d22),
-		23 => run_day!(y2024, d23),
-		24 => run_day!(y2024, d24),
-		25 => run_day!(y2024, d25),
-		_ => unimplemented!(),
-	}
-}
-
-pub fn run_all() {
-	(1..=25).for_each(run)
-}
+pub const SOLUTIONS: &[&dyn Solution] = &[
+	solution!(y2024, d01),
+	solution!(y2024, d02),
+	solution!(y2024, d03),
+	solution!(y2024, d04),
+	solution!(y2024, d05),
+	solution!(y2024, d06),
+	solution!(y2024, d07),
+	solution!(y2024, d08),
+	solution!(y2024, d09),
+	solution!(y2024, d10),
+	solution!(y2024, d11),
+	solution!(y2024, d12),
+	solution!(y2024, d13),
+	solution!(y2024, d14),
+	solution!(y2024, d15),
+	solution!(y2024, d16),
+	solution!(y2024, d17),
+	solution!(y2024, d18),
+	solution!(y2024, d19),
+	solution!(y2024, d20),
+	solution!(y2024, d21),
+	solution!(y2024, d22),
+	solution!(y2024, d23),
+	solution!(y2024, d24),
+	solution!(y2024, d25, placeholder = 2),
+];
@@ -1,3 +1,5 @@
+use crate::parse::{ParseError, parse_at};
+
 pub enum Direction {
 	Left,
 	Right,
@@ -8,23 +10,22 @@ pub struct Instruction {
 	step: u32,
 }
 
-pub fn parse_input(input: &str) -> Vec<Instruction> {
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
 	input
 		.lines()
 		.map(|l| {
-			if let Some(step) = l.strip_prefix('L') {
-				Instruction {
-					direction: Direction::Left,
-					step: step.parse().unwrap(),
-				}
+			let (direction, step) = if let Some(step) = l.strip_prefix('L') {
+				(Direction::Left, step)
 			} else if let Some(step) = l.strip_prefix('R') {
-				Instruction {
-					direction: Direction::Right,
-					step: step.parse().unwrap(),
-				}
+				(Direction::Right, step)
 			} else {
-				panic!("Instruction '{l}' is not valid");
-			}
+				return Err(ParseError::new(input, l, "expected an instruction starting with L or R"));
+			};
+
+			Ok(Instruction {
+				direction,
+				step: parse_at(input, step)?,
+			})
 		})
 		.collect()
 }
@@ -94,25 +95,16 @@ pub fn part2(input: &[Instruction]) -> i32 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82";
+	const EXAMPLE: &str = include_str!("../../data/examples/2025/day01-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
-		assert_eq!(part1(&parse_input(EXAMPLE)), 3);
+		assert_eq!(part1(&parse_input(EXAMPLE).unwrap()), 3);
 	}
 
 	#[test]
 	fn example_part2() {
-		assert_eq!(part2(&parse_input(EXAMPLE)), 6);
-		assert_eq!(part2(&parse_input("R1000")), 10);
+		assert_eq!(part2(&parse_input(EXAMPLE).unwrap()), 6);
+		assert_eq!(part2(&parse_input("R1000").unwrap()), 10);
 	}
 }
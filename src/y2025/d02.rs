@@ -90,7 +90,7 @@ pub fn part2(input: &[RangeInclusive<u64>]) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+	const EXAMPLE: &str = include_str!("../../data/examples/2025/day02-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -80,10 +80,7 @@ pub fn part2(input: &[Vec<u64>]) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "987654321111111
-811111111111119
-234234234234278
-818181911112111";
+	const EXAMPLE: &str = include_str!("../../data/examples/2025/day03-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
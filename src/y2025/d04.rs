@@ -100,16 +100,7 @@ pub fn part2(input: &Grid<Cell>) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
+	const EXAMPLE: &str = include_str!("../../data/examples/2025/day04-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -65,17 +65,7 @@ pub fn part2(input: &Database) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32";
+	const EXAMPLE: &str = include_str!("../../data/examples/2025/day05-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
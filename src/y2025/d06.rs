@@ -99,10 +99,7 @@ pub fn part2(input: &[String]) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "123 328  51 64 
- 45 64  387 23 
-  6 98  215 314
-*   +   *   +";
+	const EXAMPLE: &str = include_str!("../../data/examples/2025/day06-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
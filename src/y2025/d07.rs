@@ -1,4 +1,4 @@
-use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque, hash_map::Entry};
 
 use crate::collections::{Grid, Point, Vector};
 
@@ -144,22 +144,7 @@ pub fn part2(input: &Grid<Cell>) -> usize {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
+	const EXAMPLE: &str = include_str!("../../data/examples/2025/day07-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -27,7 +27,7 @@ fn solve1(input: &[Point3D], wires: usize) -> u64 {
 		}
 	}
 
-	distances.sort_by(|a, b| a.1.cmp(&b.1));
+	distances.sort_by_key(|a| a.1);
 
 	let mut connections: HashMap<Point3D, Vec<Point3D>> = HashMap::new();
 	for (pair, _) in &distances[..wires] {
@@ -73,7 +73,7 @@ pub fn part2(input: &[Point3D]) -> u64 {
 		}
 	}
 
-	distances.sort_by(|a, b| a.1.cmp(&b.1));
+	distances.sort_by_key(|a| a.1);
 
 	let mut connected = HashSet::new();
 	for (pair, _) in &distances {
@@ -92,26 +92,7 @@ pub fn part2(input: &[Point3D]) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689";
+	const EXAMPLE: &str = include_str!("../../data/examples/2025/day08-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
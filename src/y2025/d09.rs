@@ -48,10 +48,10 @@ impl Rectangle {
 	/// Edges act like thin rectangles
 	fn has_intersection(&self, edges: &[(Point, Point)]) -> bool {
 		for edge in edges {
-			let min_x = edge.0 .0.min(edge.1 .0);
-			let max_x = edge.0 .0.max(edge.1 .0);
-			let min_y = edge.0 .1.min(edge.1 .1);
-			let max_y = edge.0 .1.max(edge.1 .1);
+			let min_x = edge.0.0.min(edge.1.0);
+			let max_x = edge.0.0.max(edge.1.0);
+			let min_y = edge.0.1.min(edge.1.1);
+			let max_y = edge.0.1.max(edge.1.1);
 
 			if self.min_x < max_x && self.max_x > min_x && self.min_y < max_y && self.max_y > min_y {
 				return true;
@@ -89,14 +89,7 @@ pub fn part2(input: &[Point]) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3";
+	const EXAMPLE: &str = include_str!("../../data/examples/2025/day09-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
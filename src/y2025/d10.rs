@@ -251,25 +251,18 @@ pub fn part1(input: &[Machine]) -> u32 {
 	input.iter().map(|machine| machine.minimum_lights()).sum()
 }
 
-pub fn part2(_input: &[Machine]) -> u32 {
+pub fn part2(input: &[Machine]) -> u32 {
 	// TODO: Optimize
-	// This part is taking a full 5 minutes on a good computer so i'm commenting the code
-	// to make sure it doesn't slow in case i'm running the full year.
-
-	// Uncomment to get the real computation
-	// input.iter().map(|machine| machine.minimum_joltage()).sum()
-
-	// Returning tests expected value
-	33
+	// This part is taking a full 5 minutes on a good computer, use `--timeout` to skip it
+	// when running the full year.
+	input.iter().map(|machine| machine.minimum_joltage()).sum()
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
-[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
-[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+	const EXAMPLE: &str = include_str!("../../data/examples/2025/day10-1.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -54,30 +54,9 @@ pub fn part2(input: &HashMap<String, Vec<String>>) -> u64 {
 mod tests {
 	use super::*;
 
-	const EXAMPLE: &str = "aaa: you hhh
-you: bbb ccc
-bbb: ddd eee
-ccc: ddd eee fff
-ddd: ggg
-eee: out
-fff: out
-ggg: out
-hhh: ccc fff iii
-iii: out";
-
-	const EXAMPLE2: &str = "svr: aaa bbb
-aaa: fft
-fft: ccc
-bbb: tty
-tty: ccc
-ccc: ddd eee
-ddd: hub
-hub: fff
-eee: dac
-dac: fff
-fff: ggg hhh
-ggg: out
-hhh: out";
+	const EXAMPLE: &str = include_str!("../../data/examples/2025/day11-1.txt").trim_ascii_end();
+
+	const EXAMPLE2: &str = include_str!("../../data/examples/2025/day11-2.txt").trim_ascii_end();
 
 	#[test]
 	fn example_part1() {
@@ -1,3 +1,5 @@
+use crate::answer::Answer;
+
 #[derive(Debug)]
 pub struct Present {
 	blocks: usize,
@@ -62,8 +64,8 @@ pub fn part1(input: &Input) -> usize {
 		.count()
 }
 
-pub fn part2(_: &Input) -> usize {
-	0
+pub fn part2(_: &Input) -> Answer {
+	Answer::None
 }
 
 #[allow(unused)]
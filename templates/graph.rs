@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+/// Nodes reachable from each node
+pub type Graph = HashMap<String, Vec<String>>;
+
+pub fn parse_input(input: &str) -> Graph {
+	input
+		.lines()
+		.map(|line| {
+			let (node, neighbours) = line.split_once(':').unwrap();
+
+			(node.to_owned(), neighbours.split_whitespace().map(String::from).collect())
+		})
+		.collect()
+}
+
+#[allow(unused_variables)]
+pub fn part1(input: &Graph) -> usize {
+	0
+}
+
+#[allow(unused_variables)]
+pub fn part2(input: &Graph) -> usize {
+	0
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = "a: b c
+b: c";
+
+	#[test]
+	fn example_part1() {
+		assert_eq!(part1(&parse_input(EXAMPLE)), 0);
+	}
+
+	#[test]
+	fn example_part2() {
+		assert_eq!(part2(&parse_input(EXAMPLE)), 0);
+	}
+}
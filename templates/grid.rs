@@ -0,0 +1,48 @@
+use crate::collections::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cell {
+	Empty,
+	Wall,
+}
+
+impl From<char> for Cell {
+	fn from(value: char) -> Self {
+		match value {
+			'#' => Self::Wall,
+			_ => Self::Empty,
+		}
+	}
+}
+
+pub fn parse_input(input: &str) -> Grid<Cell> {
+	Grid::new(input)
+}
+
+#[allow(unused_variables)]
+pub fn part1(input: &Grid<Cell>) -> usize {
+	0
+}
+
+#[allow(unused_variables)]
+pub fn part2(input: &Grid<Cell>) -> usize {
+	0
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = ".#.
+#..";
+
+	#[test]
+	fn example_part1() {
+		assert_eq!(part1(&parse_input(EXAMPLE)), 0);
+	}
+
+	#[test]
+	fn example_part2() {
+		assert_eq!(part2(&parse_input(EXAMPLE)), 0);
+	}
+}
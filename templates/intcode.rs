@@ -0,0 +1,30 @@
+use crate::y2019::intcode::{Interpreter, Program};
+
+pub fn parse_input(input: &str) -> Program {
+	Program::from(input)
+}
+
+pub fn part1(input: &Program) -> i64 {
+	Interpreter::run_with_inputs(input, &[1]).last().copied().unwrap_or_default()
+}
+
+pub fn part2(input: &Program) -> i64 {
+	Interpreter::run_with_inputs(input, &[2]).last().copied().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = "104,0,99";
+
+	#[test]
+	fn example_part1() {
+		assert_eq!(part1(&parse_input(EXAMPLE)), 0);
+	}
+
+	#[test]
+	fn example_part2() {
+		assert_eq!(part2(&parse_input(EXAMPLE)), 0);
+	}
+}
@@ -0,0 +1,30 @@
+pub fn parse_input(input: &str) -> Vec<String> {
+	input.lines().map(String::from).collect()
+}
+
+#[allow(unused_variables)]
+pub fn part1(input: &[String]) -> usize {
+	0
+}
+
+#[allow(unused_variables)]
+pub fn part2(input: &[String]) -> usize {
+	0
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = "";
+
+	#[test]
+	fn example_part1() {
+		assert_eq!(part1(&parse_input(EXAMPLE)), 0);
+	}
+
+	#[test]
+	fn example_part2() {
+		assert_eq!(part2(&parse_input(EXAMPLE)), 0);
+	}
+}
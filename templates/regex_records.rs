@@ -0,0 +1,51 @@
+use regex::Regex;
+
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Record {
+	name: String,
+	value: u32,
+}
+
+pub fn parse_input(input: &str) -> Vec<Record> {
+	let re = Regex::new(r"(\w+): (\d+)").unwrap();
+	input
+		.lines()
+		.map(|l| {
+			let caps = re.captures(l).unwrap();
+
+			Record {
+				name: caps[1].to_owned(),
+				value: caps[2].parse().unwrap(),
+			}
+		})
+		.collect()
+}
+
+#[allow(unused_variables)]
+pub fn part1(input: &[Record]) -> usize {
+	0
+}
+
+#[allow(unused_variables)]
+pub fn part2(input: &[Record]) -> usize {
+	0
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = "a: 1
+b: 2";
+
+	#[test]
+	fn example_part1() {
+		assert_eq!(part1(&parse_input(EXAMPLE)), 0);
+	}
+
+	#[test]
+	fn example_part2() {
+		assert_eq!(part2(&parse_input(EXAMPLE)), 0);
+	}
+}
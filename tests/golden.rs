@@ -0,0 +1,169 @@
+//! Golden tests, solving every registered day against its cached input and comparing the answers
+//! with the recorded ones. Days without a cached input or a recorded answer are ignored.
+//! A part with a recorded answer fails when it exceeds the time limit, which can be set in seconds
+//! with the `GOLDEN_TIMEOUT` environment variable, 0 disabling it.
+//!
+//! As the cases depend on the local inputs, they are listed at runtime instead of using the
+//! default test harness. The first argument which is neither an option nor the value of one filters
+//! the cases by name, and `--list` prints them without solving them, as expected by test runners.
+
+use std::process::ExitCode;
+use std::time::Duration;
+
+use advent_of_code::runner::{Failure, Task, quiet_panics, solve_in_order};
+use advent_of_code::verify::{AnswerStore, Verdict};
+use advent_of_code::{cached_input, solutions};
+
+/// Default time limit of each part, tests are built without optimizations
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Options of the default test harness taking a value, given as the next argument
+const VALUE_OPTIONS: &[&str] = &[
+	"--format",
+	"--color",
+	"--test-threads",
+	"--skip",
+	"--logfile",
+	"--shuffle-seed",
+	"-Z",
+];
+
+fn timeout() -> Option<Duration> {
+	match std::env::var("GOLDEN_TIMEOUT").ok().and_then(|secs| secs.parse::<u64>().ok()) {
+		Some(0) => None,
+		Some(secs) => Some(Duration::from_secs(secs)),
+		None => Some(TIMEOUT),
+	}
+}
+
+/// Filter of the cases, the first argument which is neither an option nor the value of one
+fn filter(args: &[String]) -> Option<&str> {
+	let mut args = args.iter();
+	while let Some(arg) = args.next() {
+		if VALUE_OPTIONS.contains(&arg.as_str()) {
+			args.next();
+		} else if !arg.starts_with('-') {
+			return Some(arg);
+		}
+	}
+	None
+}
+
+fn name(year: u16, day: u8) -> String {
+	format!("y{year}::d{day:02}")
+}
+
+fn main() -> ExitCode {
+	let args: Vec<String> = std::env::args().skip(1).collect();
+	let has = |flag: &str| args.iter().any(|arg| arg == flag);
+	let filter = filter(&args);
+	let selected = |name: &str| match filter {
+		Some(filter) if has("--exact") => name == filter,
+		Some(filter) => name.contains(filter),
+		None => true,
+	};
+	quiet_panics();
+
+	let mut answers = AnswerStore::default();
+	let (mut tasks, mut ignored) = (Vec::new(), Vec::new());
+	for solution in solutions() {
+		let (year, day) = (solution.year(), solution.day());
+		if !selected(&name(year, day)) {
+			continue;
+		}
+		if let Err(e) = answers.load(year) {
+			eprintln!("error: unable to read {}: {e}", answers.path(year).display());
+			return ExitCode::FAILURE;
+		}
+
+		if (1..=2).all(|part| answers.get(year, day, part).is_none()) {
+			ignored.push((name(year, day), "no recorded answer"));
+		} else {
+			match cached_input(year, day) {
+				Ok(None) => ignored.push((name(year, day), "no cached input")),
+				// An invalid cached input fails its day
+				input => tasks.push(Task {
+					solution,
+					input: input.map(Option::unwrap).map_err(|e| e.to_string()),
+					part: None,
+				}),
+			}
+		}
+	}
+
+	if has("--list") {
+		// Only the ignored cases are listed with `--ignored`
+		if has("--ignored") {
+			ignored.iter().for_each(|(name, _)| println!("{name}: test"));
+		} else {
+			tasks
+				.iter()
+				.for_each(|task| println!("{}: test", name(task.solution.year(), task.solution.day())));
+		}
+		return ExitCode::SUCCESS;
+	}
+
+	println!("\nrunning {} tests", tasks.len() + ignored.len());
+	for (name, reason) in &ignored {
+		println!("test {name} ... ignored, {reason}");
+	}
+
+	let jobs = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+	let timeout = timeout();
+	let (mut passed, mut timed_out, mut failures) = (0, 0, Vec::new());
+	solve_in_order(&tasks, jobs, timeout, |result| {
+		let name = name(result.year, result.day);
+		let verdicts = answers.verify_day(&result);
+
+		let mut wrong = Vec::new();
+		if let Some(Failure::Unavailable(reason)) = &result.parse_failure {
+			wrong.push(reason.clone());
+		}
+		for (part, (verdict, p)) in verdicts.iter().zip(result.parts()).enumerate() {
+			match (verdict, &p.answer) {
+				(Verdict::Wrong { expected }, Err(Failure::TimedOut)) => wrong.push(format!(
+					"part {}: timed out after {:?}, expected {expected}",
+					part + 1,
+					timeout.unwrap_or_default()
+				)),
+				(Verdict::Wrong { expected }, Ok(answer)) => wrong.push(format!("part {}: got {answer}, expected {expected}", part + 1)),
+				(Verdict::Wrong { expected }, Err(failure)) => wrong.push(format!("part {}: {failure}, expected {expected}", part + 1)),
+				_ => (),
+			}
+		}
+
+		if !wrong.is_empty() {
+			println!("test {name} ... FAILED");
+			failures.push((name, wrong));
+		} else if result.has_timed_out() {
+			// Only parts without a recorded answer, which are not verified
+			println!("test {name} ... ignored, timed out after {:?}", timeout.unwrap_or_default());
+			timed_out += 1;
+		} else {
+			println!("test {name} ... ok");
+			passed += 1;
+		}
+	});
+
+	if !failures.is_empty() {
+		println!("\nfailures:");
+		for (name, wrong) in &failures {
+			for w in wrong {
+				println!("    {name} {w}");
+			}
+		}
+	}
+
+	let status = if failures.is_empty() { "ok" } else { "FAILED" };
+	println!(
+		"\ntest result: {status}. {passed} passed; {} failed; {} ignored\n",
+		failures.len(),
+		ignored.len() + timed_out
+	);
+
+	if failures.is_empty() {
+		ExitCode::SUCCESS
+	} else {
+		ExitCode::FAILURE
+	}
+}